@@ -9,12 +9,12 @@ fn main() {
         eprintln!("Category:{}", category);
         // Enumerate category families.
         eprintln!(" Families");
-        for family in category.families().iter().take(CNT) {
+        for family in category.family_slice().iter().take(CNT) {
             eprintln!("  {}", family);
         }
         // Enumerate category fonts.
         eprintln!(" Fonts");
-        for font in category.fonts().iter().take(CNT) {
+        for font in category.font_slice().iter().take(CNT) {
             eprintln!("  {}", font);
         }
     }
@@ -25,12 +25,12 @@ fn main() {
         eprintln!("Subset:{}", subset);
         // Enumerate subset families.
         eprintln!(" Families");
-        for family in subset.families().iter().take(CNT) {
+        for family in subset.family_slice().iter().take(CNT) {
             eprintln!("  {}", family);
         }
         // Enumerate subset fonts.
         eprintln!(" Fonts");
-        for font in subset.fonts().iter().take(CNT) {
+        for font in subset.font_slice().iter().take(CNT) {
             eprintln!("  {}", font);
         }
     }
@@ -50,7 +50,7 @@ fn main() {
 
         // Enumerate family fonts.
         eprintln!(" Fonts");
-        for font in family.fonts().iter().take(CNT) {
+        for font in family.font_slice().iter().take(CNT) {
             eprintln!("  {}", font);
         }
 
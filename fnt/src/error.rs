@@ -15,6 +15,9 @@ use std::fmt::{Display, Formatter, Result};
 /// - `Deserialize`: Indicates an error that occurred while deserializing JSON data.
 /// - `CacheDir`: Indicates an error that occurred while interacting with the cache directory.
 /// - `CacheFile`: Indicates an error that occurred while interacting with a cache file.
+/// - `MissingFile`: Indicates a file missing from a font family's file list.
+/// - `UnknownFont`: Indicates a name which doesn't match a font family or font.
+/// - `InvalidId`: Indicates an _id_ which doesn't match an enabled font family or font.
 #[derive(Debug)]
 pub enum FontError {
     /// An error that occurred while making a network request.
@@ -76,6 +79,50 @@ pub enum FontError {
     /// }
     /// ```
     CacheFile(std::io::Error),
+
+    /// A file missing from a font family's file list.
+    ///
+    /// This variant wraps a `StringError`, which names the missing file
+    /// and the font family.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use google_fonts::FontError;
+    /// if let FontError::MissingFile(e) = error {
+    ///     println!("Missing file error: {}", e);
+    /// }
+    /// ```
+    MissingFile(StringError),
+
+    /// A name which doesn't match a font family or font.
+    ///
+    /// This variant wraps an `UnknownFontError`, which holds the name
+    /// and similar names from the catalog.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use google_fonts::FontError;
+    /// if let FontError::UnknownFont(e) = error {
+    ///     println!("Did you mean: {:?}", e.suggestions());
+    /// }
+    /// ```
+    UnknownFont(UnknownFontError),
+
+    /// An _id_ which doesn't match an enabled font family or font.
+    ///
+    /// This variant wraps a `StringError`, which names the _id_.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use google_fonts::FontError;
+    /// if let FontError::InvalidId(e) = error {
+    ///     println!("Invalid id error: {}", e);
+    /// }
+    /// ```
+    InvalidId(StringError),
 }
 
 impl Display for FontError {
@@ -85,6 +132,9 @@ impl Display for FontError {
             FontError::Deserialize(e) => write!(f, "deserialization error: {}", e),
             FontError::CacheDir(e) => write!(f, "font cache directory error: {}", e),
             FontError::CacheFile(e) => write!(f, "font cache file error: {}", e),
+            FontError::MissingFile(e) => write!(f, "font missing file error: {}", e),
+            FontError::UnknownFont(e) => write!(f, "unknown font error: {}", e),
+            FontError::InvalidId(e) => write!(f, "invalid font id error: {}", e),
         }
     }
 }
@@ -96,6 +146,9 @@ impl Error for FontError {
             FontError::Deserialize(e) => Some(e),
             FontError::CacheDir(e) => Some(e),
             FontError::CacheFile(e) => Some(e),
+            FontError::MissingFile(e) => Some(e),
+            FontError::UnknownFont(e) => Some(e),
+            FontError::InvalidId(e) => Some(e),
         }
     }
 }
@@ -118,3 +171,37 @@ impl Display for StringError {
 }
 impl Error for StringError {}
 
+#[derive(Debug)]
+pub struct UnknownFontError {
+    name: String,
+    suggestions: Vec<String>,
+}
+impl UnknownFontError {
+    pub fn new(name: &str, suggestions: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            suggestions,
+        }
+    }
+
+    /// The name which didn't match.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Similar names from the catalog, closest first.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+impl Display for UnknownFontError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "no font named '{}'", self.name)?;
+        if !self.suggestions.is_empty() {
+            write!(f, "; did you mean '{}'?", self.suggestions.join("', '"))?;
+        }
+        Ok(())
+    }
+}
+impl Error for UnknownFontError {}
+
@@ -1,35 +1,50 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use heck::ToTitleCase;
 use lazy_static::lazy_static;
 use rayon::prelude::*;
 use reqwest::blocking::Client;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Node, Selector};
 use serde::{Deserialize, Serialize};
 use skia_safe::{surfaces, Color, EncodedImageFormat, Font, FontMgr, Paint};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fs::{self, File},
     io::{BufReader, Read, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{Arc, RwLock},
     thread,
     time::Duration,
 };
+use ttf_parser::Face;
 
 pub const FAMILY: &str = "Family";
 pub const FONT: &str = "Font";
 pub const CATEGORY: &str = "Category";
 pub const SUBSET: &str = "Subset";
+pub const SCRIPT: &str = "Script";
+pub const COLOR_CAPABILITY: &str = "ColorCapability";
+pub const STROKE: &str = "Stroke";
+pub const CLASSIFICATION: &str = "Classification";
+pub const LICENSE: &str = "License";
+pub const DESIGNER: &str = "Designer";
 pub const VARIABLE: &str = "variable";
 pub const STATIC: &str = "static";
 pub const FULL: &str = "full";
+pub const ALL_SUBSETS: &str = "all-subsets";
+pub const ALL_CATEGORIES: &str = "all-categories";
+pub const DESCRIPTIONS: &str = "descriptions";
+pub const SAMPLE_TEXT: &str = "sample-text";
+pub const VERSION: &str = "0.2.0"; // Subset and category features changed what `variable` and `static` enable.
 pub const TAKE: usize = usize::MAX;
 pub const FAMILY_ID_INCREMENT: u32 = 1000; // The Roboto Serif font family has 721 fonts.
+pub const ID_REGISTRY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/ids.json"); // Checked in to keep ids stable.
+pub const FNT_SRC: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../fnt/src/");
+pub const DOC_IMGS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../doc/imgs");
 pub const MAX_RETRIES: usize = 9;
 pub const RETRY_DELAY: Duration = Duration::from_millis(500);
 
 pub fn main() -> Result<()> {
-    build("../fnt/src/", true)
+    build(FNT_SRC, true)
 }
 
 pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
@@ -53,7 +68,6 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
                 name: o.clone(),
                 variant: o.replace(' ', ""),
                 fams: Vec::new(),
-                fnts: Vec::new(),
             })
         })
         .collect();
@@ -69,14 +83,122 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
                 name: o.clone(),
                 variant: sub_variant(o),
                 fams: Vec::new(),
-                fnts: Vec::new(),
+            })
+        })
+        .collect();
+
+    // Create script list.
+    let mut scr_codes: Vec<String> = fam_metas
+        .iter()
+        .filter_map(|o| o.primary_script.clone())
+        .collect();
+    scr_codes.sort_unstable();
+    scr_codes.dedup();
+    let scrs: Vec<Arc<RwLock<Scr>>> = scr_codes
+        .into_iter()
+        .map(|o| {
+            rc(Scr {
+                name: scr_name(&o),
+                variant: o.clone(),
+                code: o,
+                fams: Vec::new(),
+            })
+        })
+        .collect();
+
+    // Create color capability list.
+    let mut clr_names: Vec<String> = fam_metas
+        .iter()
+        .flat_map(|o| o.color_capabilities.clone())
+        .collect();
+    clr_names.sort_unstable();
+    clr_names.dedup();
+    let clrs: Vec<Arc<RwLock<Clr>>> = clr_names
+        .into_iter()
+        .map(|o| {
+            rc(Clr {
+                name: o.clone(),
+                variant: o.replace(|c: char| !c.is_ascii_alphanumeric(), ""),
+                fams: Vec::new(),
+            })
+        })
+        .collect();
+
+    // Create stroke list.
+    let mut stk_names: Vec<String> = fam_metas.iter().filter_map(|o| o.stroke.clone()).collect();
+    stk_names.sort_unstable();
+    stk_names.dedup();
+    let stks: Vec<Arc<RwLock<Stk>>> = stk_names
+        .into_iter()
+        .map(|o| {
+            rc(Stk {
+                name: o.clone(),
+                variant: sub_variant(o),
+                fams: Vec::new(),
+            })
+        })
+        .collect();
+
+    // Create classification list.
+    let mut cls_names: Vec<String> = fam_metas
+        .iter()
+        .flat_map(|o| o.classifications.clone())
+        .collect();
+    cls_names.sort_unstable();
+    cls_names.dedup();
+    let clss: Vec<Arc<RwLock<Cls>>> = cls_names
+        .into_iter()
+        .map(|o| {
+            rc(Cls {
+                name: o.to_title_case(),
+                variant: sub_variant(o.clone()),
+                meta_name: o,
+                fams: Vec::new(),
+            })
+        })
+        .collect();
+
+    // Create license list.
+    let lics: Vec<Arc<RwLock<Lic>>> = LICENSES
+        .iter()
+        .map(|(file_name, variant, name, url)| {
+            rc(Lic {
+                name: name.to_string(),
+                variant: variant.to_string(),
+                url: url.to_string(),
+                file_name: file_name.to_string(),
+                fams: Vec::new(),
+            })
+        })
+        .collect();
+
+    // Create designer list.
+    let mut dsg_names: Vec<String> = fam_metas.iter().flat_map(|o| o.designers.clone()).collect();
+    dsg_names.sort_unstable();
+    dsg_names.dedup();
+    let mut dsg_variants_cnt: HashMap<String, u8> = HashMap::with_capacity(dsg_names.len());
+    let dsgs: Vec<Arc<RwLock<Dsg>>> = dsg_names
+        .into_iter()
+        .map(|o| {
+            // Check for designer name collision.
+            // Occurs for names differing only by accents or punctuation.
+            let mut variant = dsg_variant(&o);
+            *dsg_variants_cnt.entry(variant.clone()).or_default() += 1;
+            let cnt = dsg_variants_cnt[&variant];
+            if cnt != 1 {
+                variant.push_str(&cnt.to_string());
+            }
+            rc(Dsg {
+                name: o,
+                variant,
+                fams: Vec::new(),
             })
         })
         .collect();
 
     // Create families list.
     let mut fams: Vec<Arc<RwLock<Fam>>> = Vec::with_capacity(fam_metas.len());
-    let mut id: u32 = 0;
+    let mut ids = IdRegistry::load(ID_REGISTRY)?;
     for fam_meta in fam_metas.iter() {
         // Find Cat.
         let cat = cats
@@ -84,22 +206,52 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
             .find(|o| o.read().unwrap().name == fam_meta.category)
             .unwrap();
 
+        // Find Scr.
+        let scr = scrs
+            .iter()
+            .find(|o| Some(&o.read().unwrap().code) == fam_meta.primary_script.as_ref())
+            .cloned();
+
+        // Find Stk.
+        let stk = stks
+            .iter()
+            .find(|o| Some(&o.read().unwrap().name) == fam_meta.stroke.as_ref())
+            .cloned();
+
         // Create Fam.
         let name = fam_meta.family.clone();
         let variant = name.replace(' ', "");
+        let id = ids.family_id(&variant);
         let fam = rc(Fam {
             id,
+            row: fams.len(),
             name,
             variant,
             cat: cat.clone(),
+            scr: scr.clone(),
+            stk: stk.clone(),
+            lic: None,
             meta: fam_meta.clone(),
             fnts: Vec::new(),
             subs: Vec::new(),
+            clrs: Vec::new(),
+            clss: Vec::new(),
+            dsgs: Vec::new(),
         });
 
         // Associate Cat.
         cat.write().unwrap().fams.push(fam.clone());
 
+        // Associate Scr.
+        if let Some(scr) = scr {
+            scr.write().unwrap().fams.push(fam.clone());
+        }
+
+        // Associate Stk.
+        if let Some(stk) = stk {
+            stk.write().unwrap().fams.push(fam.clone());
+        }
+
         // Associate Subs.
         for sub in subs
             .iter()
@@ -109,8 +261,36 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
             fam.write().unwrap().subs.push(sub.clone());
         }
 
+        // Associate Clrs.
+        for clr in clrs.iter().filter(|o| {
+            fam_meta
+                .color_capabilities
+                .contains(&o.read().unwrap().name)
+        }) {
+            clr.write().unwrap().fams.push(fam.clone());
+            fam.write().unwrap().clrs.push(clr.clone());
+        }
+
+        // Associate Clss.
+        for cls in clss.iter().filter(|o| {
+            fam_meta
+                .classifications
+                .contains(&o.read().unwrap().meta_name)
+        }) {
+            cls.write().unwrap().fams.push(fam.clone());
+            fam.write().unwrap().clss.push(cls.clone());
+        }
+
+        // Associate Dsgs.
+        // Keep the designer order of the metadata.
+        for dsg_name in fam_meta.designers.iter() {
+            if let Some(dsg) = dsgs.iter().find(|o| o.read().unwrap().name == *dsg_name) {
+                dsg.write().unwrap().fams.push(fam.clone());
+                fam.write().unwrap().dsgs.push(dsg.clone());
+            }
+        }
+
         fams.push(fam);
-        id += FAMILY_ID_INCREMENT;
     }
 
     // Create font list.
@@ -118,7 +298,36 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
     let mut fnt_variants_cnt: HashMap<String, u8> = HashMap::with_capacity(fam_metas.len());
     for fam in fams.iter_mut() {
         // Get file list for network.
-        let fnt_fles = fam.read().unwrap().get_file_list(&cli)?.file_refs;
+        let man = fam.read().unwrap().get_file_list(&cli)?;
+
+        // Find Lic from the license file name.
+        let lic = lics
+            .iter()
+            .find(|o| {
+                man.files.iter().any(|f| {
+                    f.filename
+                        .eq_ignore_ascii_case(&o.read().unwrap().file_name)
+                })
+            })
+            .ok_or_else(|| {
+                let lic_names: Vec<String> = lics
+                    .iter()
+                    .map(|o| o.read().unwrap().file_name.clone())
+                    .collect();
+                let fle_names: Vec<&str> = man.files.iter().map(|o| o.filename.as_str()).collect();
+                anyhow!(
+                    "missing license file for {}: expected one of {}, found {}",
+                    fam.read().unwrap().name,
+                    lic_names.join(", "),
+                    fle_names.join(", ")
+                )
+            })?;
+
+        // Associate Lic.
+        lic.write().unwrap().fams.push(fam.clone());
+        fam.write().unwrap().lic = Some(lic.clone());
+
+        let fnt_fles = man.file_refs;
         for (idx_fnt_fle, fnt_fle) in fnt_fles.iter().enumerate() {
             // Clean font name.
             // Remove file suffix `.ttf` from filename: ABeeZee-Regular.ttf.
@@ -130,25 +339,28 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
                 name = name[..idx_fnd + 8].to_string();
             }
 
+            // Check for font name collision.
+            // Occurs for "Asap CondensedLight", "AsapCondensed Light".
+            let mut variant = name.replace('-', "");
+            let cnt = fnt_variants_cnt.entry(variant.clone()).or_default();
+            *cnt += 1;
+            if *cnt != 1 {
+                // Append numeric suffix to font name.
+                variant.push_str(&cnt.to_string());
+            }
+
             // Create a font.
-            let mut fnt = Fnt {
-                id: idx_fnt_fle as u32,
+            let fnt = rc(Fnt {
+                id: ids.font_id(&fam.read().unwrap().variant, &variant)?,
+                fle_idx: idx_fnt_fle,
+                row: fnts.len(),
                 name: name.replace('-', " "),
-                variant: name.replace('-', ""),
+                variant,
                 fam: fam.clone(),
                 subs: Vec::new(),
-            };
-
-            // Check for font name collision.
-            // Occurs for "Asap CondensedLight", "AsapCondensed Light".
-            *fnt_variants_cnt.entry(fnt.variant.clone()).or_default() += 1;
-            if let Some(cnt) = fnt_variants_cnt.get(&fnt.variant) {
-                if *cnt != 1 {
-                    // Append numeric suffix to font name.
-                    fnt.variant.push_str(&cnt.to_string());
-                }
-            }
-            let fnt = rc(fnt);
+                mtr: None,
+                fle_name: fnt_fle.filename.clone(),
+            });
 
             // Associate Subs.
             for sub in subs.iter().filter(|o| {
@@ -161,22 +373,24 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
                     .subsets
                     .contains(&o.read().unwrap().name)
             }) {
-                sub.write().unwrap().fnts.push(fnt.clone());
                 fnt.write().unwrap().subs.push(sub.clone());
             }
 
             fam.write().unwrap().fnts.push(fnt.clone());
-            fam.write()
-                .unwrap()
-                .cat
-                .write()
-                .unwrap()
-                .fnts
-                .push(fnt.clone());
             fnts.push(fnt);
         }
     }
 
+    // Get font metrics.
+    // Downloads font files which aren't cached yet.
+    let mtrs = fnts
+        .par_iter()
+        .map(|o| o.read().unwrap().metrics(&cli))
+        .collect::<Result<Vec<Mtr>>>()?;
+    for (fnt, mtr) in fnts.iter().zip(mtrs) {
+        fnt.write().unwrap().mtr = Some(mtr);
+    }
+
     // Write files.
 
     // Write Error file.
@@ -198,9 +412,69 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
     fs::write(format!("{}category.rs", pth), buf)?;
 
     let mut buf = String::with_capacity(1 << 20); // 1MB
-    wrt_fle_subset(&subs, &mut buf);
+    wrt_fle_subset(&subs, &cli, &mut buf)?;
     fs::write(format!("{}subset.rs", pth), buf)?;
 
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_script(&scrs, &mut buf);
+    fs::write(format!("{}script.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_color(&clrs, &mut buf);
+    fs::write(format!("{}color.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_stroke(&stks, &mut buf);
+    fs::write(format!("{}stroke.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_classification(&clss, &mut buf);
+    fs::write(format!("{}classification.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_license(&lics, &mut buf);
+    fs::write(format!("{}license.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_sample(&mut buf);
+    fs::write(format!("{}sample.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_designer(&dsgs, &mut buf);
+    fs::write(format!("{}designer.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_language(&mut buf);
+    fs::write(format!("{}language.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_codepoint(&mut buf);
+    fs::write(format!("{}codepoint.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_cover(&mut buf);
+    fs::write(format!("{}cover.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_fallback(&mut buf);
+    fs::write(format!("{}fallback.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_name(&mut buf);
+    fs::write(format!("{}name.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_recommend(&get_script_ranges(&cli)?, &mut buf);
+    fs::write(format!("{}recommend.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_search(&fams, &cli, &mut buf)?;
+    fs::write(format!("{}search.rs", pth), buf)?;
+
+    let mut buf = String::with_capacity(1 << 20); // 1MB
+    wrt_fle_query(&mut buf);
+    fs::write(format!("{}query.rs", pth), buf)?;
+
     let mut buf = String::with_capacity(1 << 20); // 1MB
     wrt_fle_lib(&fnts, &mut buf);
     fs::write(format!("{}lib.rs", pth), buf)?;
@@ -209,7 +483,10 @@ pub fn build(pth: &str, wrt_imgs: bool) -> Result<()> {
         wrt_fle_imgs(&fnts, &cli)?;
     }
 
-    wrt_fle_cargo_toml(pth)?;
+    wrt_fle_cargo_toml(pth, &subs, &cats)?;
+
+    // Save new ids once every file is written.
+    ids.save(ID_REGISTRY)?;
 
     Ok(())
 }
@@ -220,23 +497,38 @@ pub fn wrt_fle_family(fams: &[Arc<RwLock<Fam>>], cli: &Client, buf: &mut String)
     //     ABeeZee,
     // }
     buf.push_str(r#"
+use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fs;
 use std::ops::RangeInclusive;
-use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
-use crate::font::Font;
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr, IntoEnumIterator};
+use crate::error::{FontError, StringError, UnknownFontError};
+use crate::font::{FamilyFileList, Font, FontStyle};
+use crate::name::{normalize_name, suggestions};
 use crate::category::Category;
+use crate::license::License;
+#[cfg(feature = "sample-text")]
+use crate::sample::{GlyphGroup, SampleText};
+use crate::classification::Classification;
+use crate::color::ColorCapability;
+use crate::designer::Designer;
+use crate::language::LanguageTag;
+use crate::script::Script;
+use crate::stroke::Stroke;
 use crate::subset::Subset;
 
 /// The _family id_ increment.
 /// 
 /// The Roboto Serif font family has 721 fonts.
-pub const ID_INCREMENT: u32 = 1000;
+#[deprecated(since = "0.2.0", note = "an implementation detail of font ids")]
+pub const ID_INCREMENT: u32 = FAMILY_ID_INCREMENT;
 
 /// An _enumeration_ of [Google font](https://fonts.google.com) families.
 /// 
 /// A font family may have one or more fonts with different styles and sizes.
 #[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
-#[repr(u32)] // for `mem::transmute`
+#[repr(u32)] // for `id`
 "#);
     buf.push_str(&format!("pub enum {} {{\n", FAMILY));
     for fam in fams.iter() {
@@ -292,173 +584,854 @@ pub const ID_INCREMENT: u32 = 1000;
         *self as u32
     }
 
-    /// Returns the first [`Font`].
+    /// Returns the first [`Font`] enabled by crate features.
     pub fn font(&self) -> Font {
-       Font::from_id(self.id())
+        self.font_slice()[0]
     }
 
-"#,
-    );
+    /// Returns the [`Font`] closest to a weight, style and stretch.
+    ///
+    /// Follows the [CSS Fonts Level 4](https://www.w3.org/TR/css-fonts-4/#font-style-matching)
+    /// font matching algorithm. Fonts are narrowed by stretch, then style, then weight.
+    /// `stretch` is a percentage of normal width. For example, `100.0`.
+    ///
+    /// A _variable_ font is preferred over an equally close _static_ font.
+    pub fn best_match(&self, weight: u16, style: FontStyle, stretch: f32) -> Font {
+        let mut fnts = self.font_slice().to_vec();
 
-    // Write `from_id`.
-    buf.push_str("    /// Transforms an _id_ into a [`Family`].\n");
-    buf.push_str("    pub(crate) fn from_id(id: u32) -> Self {\n");
-    buf.push_str("        unsafe { std::mem::transmute(id) }\n");
-    buf.push_str("    }\n"); // end from_id
+        // Narrow by stretch.
+        let key = |fnt: &Font| stretch_key(fnt.stretch_range(), stretch);
+        let best = fnts
+            .iter()
+            .map(key)
+            .min_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)))
+            .unwrap();
+        fnts.retain(|o| key(o) == best);
 
-    // Write `name`.
-    buf.push('\n');
-    buf.push_str("    /// The name of the font [`Family`] with spaces.\n");
-    buf.push_str("    pub fn name(&self) -> String {\n");
-    buf.push_str("        match self {\n");
-    for fam in fams.iter() {
-        buf.push_str(&cfg_feature("            ", fam.read().unwrap().features()));
-        buf.push_str(&format!(
-            "            {}::{} => \"{}\".into(),\n",
-            FAMILY,
-            fam.read().unwrap().variant,
-            fam.read().unwrap().name
-        ));
+        // Narrow by style.
+        // Oblique falls back to italic as fonts don't distinguish the two.
+        let italic = style != FontStyle::Normal;
+        if fnts.iter().any(|o| o.is_italic() == italic) {
+            fnts.retain(|o| o.is_italic() == italic);
+        }
+
+        // Pick by weight.
+        fnts.into_iter()
+            .min_by_key(|o| (weight_key(o.weight_range(), weight), o.is_static()))
+            .unwrap()
     }
-    buf.push_str("        }\n");
-    buf.push_str("    }\n");
 
-    // Write `fonts`.
-    buf.push('\n');
-    buf.push_str("    /// Returns fonts for the [`Family`].\n");
-    buf.push_str(&format!("    pub fn fonts(&self) -> Vec<{}> {{\n", FONT));
-    buf.push_str("        match self {\n");
-    for fam in fams.iter() {
-        buf.push_str(&cfg_feature("            ", fam.read().unwrap().features()));
-        buf.push_str(&format!(
-            "            {}::{} => {{\n",
-            FAMILY,
-            fam.read().unwrap().variant
-        ));
-        buf.push_str("                vec![\n");
-        for fnt in fam.read().unwrap().fnts.iter() {
-            buf.push_str(&cfg_feature(
-                "                    ",
-                fnt.read().unwrap().features(),
-            ));
-            buf.push_str(&format!(
-                "                    {}::{},\n",
-                FONT,
-                fnt.read().unwrap().variant
-            ));
-        }
-        buf.push_str("                ]\n");
-        buf.push_str("            }\n");
+    /// Returns the [`Family`] with a human readable name.
+    ///
+    /// Ignores case, spaces, hyphens and underscores.
+    /// For example, `Noto Sans JP` and `noto-sans-jp`.
+    pub fn from_name(name: &str) -> Result<Family, FontError> {
+        let key = normalize_name(name);
+        Family::iter()
+            .find(|o| normalize_name(o.as_ref()) == key || normalize_name(&o.display_name()) == key)
+            .ok_or_else(|| {
+                FontError::UnknownFont(UnknownFontError::new(
+                    name,
+                    suggestions(&key, Family::iter().map(|o| o.display_name())),
+                ))
+            })
     }
-    buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end fonts
 
-    // Write `category`.
-    buf.push_str(&format!("    /// Returns the font [`{}`].\n", CATEGORY));
-    buf.push_str(&format!(
-        "    pub fn {}(&self) -> {} {{\n",
-        CATEGORY.to_lowercase(),
-        CATEGORY
-    ));
-    buf.push_str("        match self {\n");
-    for fam in fams.iter() {
-        buf.push_str(&cfg_feature("            ", fam.read().unwrap().features()));
-        buf.push_str(&format!(
-            "            {}::{} => {}::{},\n",
-            FAMILY,
-            fam.read().unwrap().variant,
-            CATEGORY,
-            fam.read().unwrap().cat.read().unwrap().variant
-        ));
+    /// Get the file list for the [`Family`] from the network.
+    pub(crate) fn get_file_list(&self) -> Result<FamilyFileList, FontError> {
+        let result = Client::new()
+            .get("https://fonts.google.com/download/list")
+            .query(&[("family", self.name())])
+            .send();
+        match result {
+            Err(e) => Err(FontError::Network(e)),
+            Ok(response) => match response.text() {
+                Err(e) => Err(FontError::Network(e)),
+                Ok(txt) => {
+                    // Trim leading excess characters
+                    // to allow deserialization.
+                    //  ")]}'\n{\n
+                    let mut txt: &str = txt.as_ref();
+                    if let Some(idx) = txt.find('{') {
+                        if idx != 0 {
+                            txt = &txt[idx..];
+                        }
+                    }
+
+                    // Deserialize the file info.
+                    match serde_json::from_str::<FamilyFileList>(txt) {
+                        Err(e) => Err(FontError::Deserialize(e)),
+                        Ok(file_info) => Ok(file_info),
+                    }
+                }
+            },
+        }
     }
-    buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end `category`
 
-    // Write `coverage`
-    buf.push('\n');
-    buf.push_str("    /// Unicode characters supported by the [`Family`].\n");
-    buf.push_str(&format!(
-        "    pub fn coverage(&self) -> Vec<({}, Vec<RangeInclusive<u32>>)> {{\n",
-        SUBSET
-    ));
-    buf.push_str("        match self {\n");
-    for fam in fams.iter() {
-        let dtl = fam.read().unwrap().get_metadata_detail(cli)?;
+    /// Get the [`License`] text from the network.
+    pub fn get_license_text(&self) -> Result<String, FontError> {
+        let file_name = self.license().file_name();
+        match self.get_file_list() {
+            Err(e) => Err(e),
+            Ok(file_info) => match file_info
+                .manifest
+                .files
+                .into_iter()
+                .find(|o| o.filename.eq_ignore_ascii_case(&file_name))
+            {
+                None => Err(FontError::MissingFile(StringError::new(&format!(
+                    "Missing {} for {}",
+                    file_name,
+                    self.name()
+                )))),
+                Some(file) => Ok(file.contents),
+            },
+        }
+    }
 
-        buf.push_str(&cfg_feature("            ", fam.read().unwrap().features()));
-        buf.push_str(&format!(
-            "            {}::{} => {{\n",
-            FAMILY,
-            fam.read().unwrap().variant
-        ));
-        buf.push_str("                vec![\n");
+    /// Get the [`License`] text and store locally.
+    ///
+    /// The text is stored next to cached font files.
+    pub fn get_license_text_with_cache(&self) -> Result<String, FontError> {
+        // Get the cache directory.
+        match dirs::cache_dir() {
+            None => Err(FontError::CacheDir(StringError::new(
+                "Missing cache directory",
+            ))),
+            Some(mut pth) => {
+                // Set the file name.
+                pth.push("google-fonts");
+                pth.push(format!("{}-{}", self, self.license().file_name()));
 
-        let mut sorted_keys: Vec<_> = dtl.coverage.keys().cloned().collect::<Vec<_>>();
-        sorted_keys.sort();
-        for key in sorted_keys {
-            buf.push_str(&format!(
-                "                    ({}::{}, vec![",
-                SUBSET,
-                sub_variant(key.clone())
-            ));
+                if pth.exists() {
+                    // Load the license file from disk.
+                    match fs::read_to_string(pth) {
+                        Err(e) => Err(FontError::CacheFile(e)),
+                        Ok(txt) => Ok(txt),
+                    }
+                } else {
+                    // Create the cache directory if necessary.
+                    if let Some(directory) = pth.parent() {
+                        if !directory.exists() {
+                            if let Err(e) = fs::create_dir_all(directory) {
+                                return Err(FontError::CacheFile(e));
+                            }
+                        }
+                    }
 
-            // Parse unicode character ranges.
-            for (idx, prt) in dtl.coverage.get(&key).unwrap().split(',').enumerate() {
-                let mut prts = prt.split('-').map(|s| s.parse::<u32>().unwrap());
-                let fst = prts.next().unwrap();
-                let lst = match prts.next() {
-                    Some(lst_val) => lst_val,
-                    None => fst,
-                };
-                // ranges.push(start..=end);
-                let cma = if idx == 0 { "" } else { "," };
-                buf.push_str(&format!("{}{}..={}", cma, fst, lst));
+                    // Get the license text from the network.
+                    match self.get_license_text() {
+                        Err(err) => Err(err),
+                        // Write the license text to disk.
+                        Ok(txt) => match fs::write(pth, &txt) {
+                            Err(e) => Err(FontError::CacheFile(e)),
+                            Ok(_) => Ok(txt),
+                        },
+                    }
+                }
             }
-
-            buf.push_str("]),\n");
         }
+    }
+
+"#,
+    );
 
-        buf.push_str("                ]\n");
-        buf.push_str("            }\n");
+    // Write accessors.
+    buf.push_str(
+        r#"    /// Returns the catalog row of the [`Family`].
+    fn row(&self) -> &'static FamilyRow {
+        family_row(self.id()).expect("every family has a row")
     }
-    buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end coverage
 
-    buf.push_str("}\n"); // end impl Family
+    /// The name of the font [`Family`] with spaces.
+    pub fn name(&self) -> String {
+        self.row().name.into()
+    }
 
-    Ok(())
-}
+    /// The display name of the font [`Family`].
+    ///
+    /// Same as the name unless Google Fonts displays the family differently.
+    pub fn display_name(&self) -> String {
+        self.row().display_name.into()
+    }
 
-pub fn wrt_fle_font(fnts: &[Arc<RwLock<Fnt>>], buf: &mut String) {
-    // Write enum.
-    // pub enum Font {
-    //     ABeeZeeRegular,
-    // }
-    buf.push_str(r#"
-use crate::category::Category;
-use crate::error::{FontError, StringError};
-use crate::family::{Family, ID_INCREMENT};
-use reqwest::blocking::Client;
-use serde::{Deserialize, Serialize};
-use std::fs;
-use strum::{AsRefStr, Display, EnumCount, EnumIter, EnumString};
+    /// Returns fonts for the [`Family`].
+    pub fn font_slice(&self) -> &'static [Font] {
+        self.row().fonts
+    }
 
-/// An _enumeration_ of [Google fonts](https://fonts.google.com).
-#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
-#[repr(u32)] // for `mem::transmute`
-pub enum Font {
-"#);
-    let mut id: u16 = 0;
-    let mut fam_prv_o: Option<Arc<RwLock<Fam>>> = None;
-    for fnt in fnts.iter() {
-        if let Some(fam_prv) = fam_prv_o {
-            if fnt.read().unwrap().fam.read().unwrap().variant == fam_prv.read().unwrap().variant {
-                id += 1;
-            } else {
-                id = 0;
+    /// Returns fonts for the [`Family`].
+    #[deprecated(since = "0.2.0", note = "use `font_slice`, which doesn't allocate")]
+    pub fn fonts(&self) -> Vec<Font> {
+        self.font_slice().to_vec()
+    }
+
+    /// Returns the font [`Category`].
+    pub fn category(&self) -> Category {
+        self.row().category
+    }
+
+    /// Unicode characters supported by the [`Family`].
+    pub fn coverage(&self) -> Vec<(Subset, Vec<RangeInclusive<u32>>)> {
+        self.row()
+            .coverage
+            .iter()
+            .map(|(sub, rngs)| (*sub, rngs.iter().map(|&(fst, lst)| fst..=lst).collect()))
+            .collect()
+    }
+
+    /// Unicode codepoint ranges supported by the [`Family`].
+    ///
+    /// Ranges are inclusive, sorted and merged across subsets.
+    pub fn codepoint_ranges(&self) -> &'static [(u32, u32)] {
+        self.row().codepoint_ranges
+    }
+
+    /// Indicates whether the [`Family`] supports a character.
+    pub fn supports_char(&self, c: char) -> bool {
+        let cp = c as u32;
+        self.codepoint_ranges()
+            .binary_search_by(|&(fst, lst)| {
+                if lst < cp {
+                    Ordering::Less
+                } else if fst > cp {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Indicates whether the [`Family`] supports every character of a string.
+    pub fn supports_str(&self, txt: &str) -> bool {
+        txt.chars().all(|c| self.supports_char(c))
+    }
+
+    /// Returns characters of a string the [`Family`] doesn't support.
+    ///
+    /// Each character is listed once, in order of first appearance.
+    pub fn missing_chars(&self, txt: &str) -> Vec<char> {
+        let mut ret: Vec<char> = Vec::new();
+        for c in txt.chars() {
+            if !self.supports_char(c) && !ret.contains(&c) {
+                ret.push(c);
             }
         }
+        ret
+    }
 
+    /// Languages supported by the [`Family`].
+    ///
+    /// A language is identified by a language code and a script code.
+    /// For example, `ja_Jpan`.
+    pub fn languages(&self) -> Vec<&'static str> {
+        self.language_slice().to_vec()
+    }
+
+    /// Languages supported by the [`Family`], without allocating.
+    pub fn language_slice(&self) -> &'static [&'static str] {
+        self.row().languages
+    }
+
+    /// Returns the [`Script`] the [`Family`] is primarily designed for.
+    pub fn primary_script(&self) -> Option<Script> {
+        self.row().primary_script
+    }
+
+    /// Returns the language the [`Family`] is primarily designed for.
+    ///
+    /// For example, `ja_Jpan`.
+    pub fn primary_language(&self) -> Option<LanguageTag> {
+        self.row().primary_language.map(LanguageTag)
+    }
+
+    /// The description of the [`Family`].
+    ///
+    /// Paragraphs are separated by a blank line. Links and emphasis are written as markdown.
+    #[cfg(feature = "descriptions")]
+    pub fn description(&self) -> &'static str {
+        self.row().description
+    }
+
+    /// Sample text and glyph groups for previewing the [`Family`].
+    ///
+    /// Written in a language the [`Family`] supports.
+    #[cfg(feature = "sample-text")]
+    pub fn sample_text(&self) -> SampleText {
+        self.row().sample_text
+    }
+
+    /// Indicates whether the [`Family`] is part of the [Noto](https://fonts.google.com/noto) project.
+    pub fn is_noto(&self) -> bool {
+        self.row().is_noto
+    }
+
+    /// Indicates whether the [`Family`] is a brand font.
+    pub fn is_brand_font(&self) -> bool {
+        self.row().is_brand_font
+    }
+
+    /// Indicates whether the [`Family`] is open source.
+    pub fn is_open_source(&self) -> bool {
+        self.row().is_open_source
+    }
+
+    /// Returns the [`ColorCapability`]s of the [`Family`].
+    pub fn color_capabilities(&self) -> Vec<ColorCapability> {
+        self.color_capability_slice().to_vec()
+    }
+
+    /// Returns the [`ColorCapability`]s of the [`Family`], without allocating.
+    pub fn color_capability_slice(&self) -> &'static [ColorCapability] {
+        self.row().color_capabilities
+    }
+
+    /// Returns the font [`Stroke`].
+    pub fn stroke(&self) -> Option<Stroke> {
+        self.row().stroke
+    }
+
+    /// Returns the font [`Classification`]s.
+    pub fn classifications(&self) -> Vec<Classification> {
+        self.row().classifications.to_vec()
+    }
+
+    /// Returns the font [`License`].
+    pub fn license(&self) -> License {
+        self.row().license
+    }
+
+    /// Returns the [`Designer`]s of the [`Family`].
+    pub fn designers(&self) -> Vec<Designer> {
+        self.row().designers.to_vec()
+    }
+
+    /// Returns the [`Subset`]s supported by the [`Family`].
+    pub fn subsets(&self) -> Vec<Subset> {
+        self.subset_slice().to_vec()
+    }
+
+    /// Returns the [`Subset`]s supported by the [`Family`], without allocating.
+    pub fn subset_slice(&self) -> &'static [Subset] {
+        self.row().subsets
+    }
+
+    /// Returns the variable font [`Axis`] ranges of the [`Family`].
+    ///
+    /// Empty for a family without _variable_ fonts.
+    pub fn axes(&self) -> Vec<Axis> {
+        self.row().axes.to_vec()
+    }
+
+    /// The popularity rank of the [`Family`] on Google Fonts.
+    ///
+    /// `1` is the most popular family.
+    pub fn popularity(&self) -> u32 {
+        self.row().popularity
+    }
+}
+
+impl TryFrom<u32> for Family {
+    type Error = FontError;
+
+    /// Transforms an _id_ into a [`Family`].
+    ///
+    /// Fails for an unknown _id_, or a family not enabled by crate features.
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        family_row(id)
+            .and_then(|o| o.family)
+            .filter(|o| o.id() == id)
+            .ok_or_else(|| FontError::InvalidId(StringError::new(&format!("no family with id {}", id))))
+    }
+}
+
+/// Returns the [`FamilyRow`] of a family _id_ or font _id_.
+fn family_row(id: u32) -> Option<&'static FamilyRow> {
+    FAMILY_ROW_IDXS
+        .get((id / FAMILY_ID_INCREMENT) as usize)
+        .and_then(|&o| FAMILY_ROWS.get(o as usize))
+}
+
+/// Returns the [`FontRow`](crate::font::FontRow) index of a font _id_.
+pub(crate) fn font_row_idx(id: u32) -> Option<usize> {
+    family_row(id)?
+        .font_rows
+        .get((id % FAMILY_ID_INCREMENT) as usize)
+        .map(|&o| o as usize)
+}
+
+/// Returns the family of a [`FamilyRow`] index, if crate features enable it.
+pub(crate) fn family_at(idx: usize) -> Option<Family> {
+    FAMILY_ROWS.get(idx).and_then(|o| o.family)
+}
+
+/// Returns the families of [`FamilyRow`] indexes enabled by crate features.
+pub(crate) fn families_at(idxs: &[u32]) -> Vec<Family> {
+    idxs.iter().filter_map(|&o| family_at(o as usize)).collect()
+}
+
+/// Returns the fonts of families.
+pub(crate) fn fonts_of(fams: &[Family]) -> Vec<Font> {
+    fams.iter().flat_map(|o| o.font_slice()).copied().collect()
+}
+
+/// Catalog data of a [`Family`].
+///
+/// One row per family replaces a `match` per accessor,
+/// which keeps the generated code small and quick to compile.
+/// Every family has a row. `family` is `None` when crate features don't enable it,
+/// so row indexes don't depend on crate features.
+/// Coverage, languages, descriptions and sample text are then empty,
+/// which keeps them out of the binary.
+pub(crate) struct FamilyRow {
+    family: Option<Family>,
+    name: &'static str,
+    display_name: &'static str,
+    fonts: &'static [Font],
+    font_rows: &'static [u32],
+    category: Category,
+    coverage: &'static [(Subset, &'static [(u32, u32)])],
+    codepoint_ranges: &'static [(u32, u32)],
+    languages: &'static [&'static str],
+    primary_script: Option<Script>,
+    primary_language: Option<&'static str>,
+    #[cfg(feature = "descriptions")]
+    description: &'static str,
+    #[cfg(feature = "sample-text")]
+    sample_text: SampleText,
+    is_noto: bool,
+    is_brand_font: bool,
+    is_open_source: bool,
+    color_capabilities: &'static [ColorCapability],
+    stroke: Option<Stroke>,
+    classifications: &'static [Classification],
+    license: License,
+    designers: &'static [Designer],
+    subsets: &'static [Subset],
+    axes: &'static [Axis],
+    popularity: u32,
+}
+
+/// The sample text of a row whose family isn't enabled by crate features.
+///
+/// Unused when every family is enabled.
+#[cfg(feature = "sample-text")]
+#[allow(dead_code)]
+const NO_SAMPLE_TEXT: SampleText = SampleText {
+    masthead_full: "",
+    masthead_partial: "",
+    styles: "",
+    tester: "",
+    poster_sm: None,
+    poster_md: None,
+    poster_lg: None,
+    languages: &[],
+    glyph_groups: &[],
+};
+"#,
+    );
+
+    // Write `FAMILY_ID_INCREMENT`.
+    buf.push('\n');
+    buf.push_str("/// The difference between consecutive family _ids_.\n");
+    buf.push_str("///\n");
+    buf.push_str("/// A font _id_ is its family _id_ plus an offset below the increment.\n");
+    buf.push_str(&format!(
+        "pub(crate) const FAMILY_ID_INCREMENT: u32 = {};\n",
+        FAMILY_ID_INCREMENT
+    ));
+
+    // Write `FAMILY_ROWS`.
+    buf.push('\n');
+    buf.push_str(&format!(
+        "/// [`{}Row`]s in [`{}`] order.\n",
+        FAMILY, FAMILY
+    ));
+    buf.push_str(&format!("static FAMILY_ROWS: &[{}Row] = &[\n", FAMILY));
+    for fam in fams.iter() {
+        buf.push_str(&fam_row(fam, cli)?);
+    }
+    buf.push_str("];\n");
+
+    // Write `FAMILY_ROW_IDXS`.
+    buf.push('\n');
+    buf.push_str(&format!(
+        "/// [`{}Row`] indexes by family _id_ divided by [`FAMILY_ID_INCREMENT`].\n",
+        FAMILY
+    ));
+    buf.push_str("///\n");
+    buf.push_str("/// `u32::MAX` for a retired _id_.\n");
+    buf.push_str(&format!(
+        "static FAMILY_ROW_IDXS: &[u32] = {};\n",
+        dense_idxs(fams.iter().map(|o| {
+            let fam = o.read().unwrap();
+            ((fam.id / FAMILY_ID_INCREMENT) as usize, fam.row)
+        }))
+    ));
+
+    // Write `Axis`.
+    buf.push_str(
+        r#"
+/// The range of a variable font axis.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Axis {
+    /// The axis tag. For example, `wght`.
+    pub tag: &'static str,
+    /// The minimum axis value.
+    pub min: f32,
+    /// The default axis value.
+    pub default: f32,
+    /// The maximum axis value.
+    pub max: f32,
+}
+
+/// Orders a stretch range by closeness to a desired stretch.
+///
+/// Narrower widths are preferred for a desired stretch at or below `100`.
+/// Wider widths are preferred otherwise.
+fn stretch_key(rng: RangeInclusive<f32>, desired: f32) -> (u8, f32) {
+    let narrow = desired <= 100.0;
+    if rng.contains(&desired) {
+        (0, 0.0)
+    } else if *rng.end() < desired {
+        (if narrow { 1 } else { 2 }, desired - rng.end())
+    } else {
+        (if narrow { 2 } else { 1 }, rng.start() - desired)
+    }
+}
+
+/// Orders a weight range by closeness to a desired weight.
+///
+/// A desired weight from `400` to `500` prefers heavier weights up to `500`,
+/// then lighter weights, then heavier weights. A lighter desired weight
+/// prefers lighter weights. A heavier desired weight prefers heavier weights.
+fn weight_key(rng: RangeInclusive<u16>, desired: u16) -> (u8, u16) {
+    let mid = (400..=500).contains(&desired);
+    if rng.contains(&desired) {
+        (0, 0)
+    } else if *rng.end() < desired {
+        (if desired < 400 { 1 } else { 2 }, desired - rng.end())
+    } else if mid {
+        (if *rng.start() <= 500 { 1 } else { 3 }, rng.start() - desired)
+    } else {
+        (if desired > 500 { 1 } else { 2 }, rng.start() - desired)
+    }
+}
+"#,
+    );
+
+    Ok(())
+}
+
+/// Writes a [`FamilyRow`] literal for a family.
+fn fam_row(fam: &Arc<RwLock<Fam>>, cli: &Client) -> Result<String> {
+    let fam = fam.read().unwrap();
+    let dtl = fam.get_metadata_detail(cli)?;
+    let mut row = String::new();
+    row.push_str("    FamilyRow {\n");
+    row.push_str(&cfg_feature("        ", fam.features()));
+    row.push_str(&format!(
+        "        family: Some({}::{}),\n",
+        FAMILY, fam.variant
+    ));
+    row.push_str(&cfg_not_feature("        ", fam.features()));
+    row.push_str("        family: None,\n");
+    row.push_str(&format!("        name: {:?},\n", fam.name));
+    row.push_str(&format!(
+        "        display_name: {:?},\n",
+        fam.display_name()
+    ));
+    row.push_str("        fonts: &[\n");
+    for fnt in fam.fnts.iter() {
+        row.push_str(&cfg_feature("            ", fnt.read().unwrap().features()));
+        row.push_str(&format!(
+            "            {}::{},\n",
+            FONT,
+            fnt.read().unwrap().variant
+        ));
+    }
+    row.push_str("        ],\n");
+    row.push_str(&format!(
+        "        font_rows: {},\n",
+        dense_idxs(fam.fnts.iter().map(|o| {
+            let fnt = o.read().unwrap();
+            (fnt.id as usize, fnt.row)
+        }))
+    ));
+    row.push_str(&format!(
+        "        category: {}::{},\n",
+        CATEGORY,
+        fam.cat.read().unwrap().variant
+    ));
+    // Heavy data is only compiled when crate features enable the family.
+    let on = cfg_predicate(fam.features());
+    let off = format!("not({})", on);
+    let mut cvg = String::from("&[\n");
+    let mut sorted_keys: Vec<_> = dtl.coverage.keys().cloned().collect::<Vec<_>>();
+    sorted_keys.sort();
+    for key in sorted_keys {
+        let rngs = parse_ranges(dtl.coverage.get(&key).unwrap());
+        cvg.push_str(&format!(
+            "            ({}::{}, &{:?}),\n",
+            SUBSET,
+            sub_variant(key.clone()),
+            rngs
+        ));
+    }
+    cvg.push_str("        ]");
+    row.push_str(&gated_field(&on, &off, "coverage", &cvg, "&[]"));
+    row.push_str(&gated_field(
+        &on,
+        &off,
+        "codepoint_ranges",
+        &format!("&{:?}", fam.codepoint_ranges(cli)?),
+        "&[]",
+    ));
+    row.push_str(&gated_field(
+        &on,
+        &off,
+        "languages",
+        &format!("&{:?}", dtl.languages),
+        "&[]",
+    ));
+    match &fam.scr {
+        Some(scr) => row.push_str(&format!(
+            "        primary_script: Some({}::{}),\n",
+            SCRIPT,
+            scr.read().unwrap().variant
+        )),
+        None => row.push_str("        primary_script: None,\n"),
+    }
+    row.push_str(&format!(
+        "        primary_language: {:?},\n",
+        fam.meta.primary_language
+    ));
+    row.push_str(&gated_field(
+        &format!("all(feature = \"{}\", {})", DESCRIPTIONS, on),
+        &format!("all(feature = \"{}\", {})", DESCRIPTIONS, off),
+        "description",
+        &format!(
+            "{:?}",
+            html_to_md(&unescaper::unescape(&dtl.description).unwrap())
+        ),
+        "\"\"",
+    ));
+    let smp = fam.get_sampletext(cli)?;
+    row.push_str(&format!(
+        "        #[cfg(all(feature = \"{}\", {}))]\n",
+        SAMPLE_TEXT, on
+    ));
+    row.push_str("        sample_text: SampleText {\n");
+    row.push_str(&format!(
+        "            masthead_full: {:?},\n",
+        smp.sample_text.masthead_full
+    ));
+    row.push_str(&format!(
+        "            masthead_partial: {:?},\n",
+        smp.sample_text.masthead_partial
+    ));
+    row.push_str(&format!(
+        "            styles: {:?},\n",
+        smp.sample_text.styles
+    ));
+    row.push_str(&format!(
+        "            tester: {:?},\n",
+        smp.sample_text.tester
+    ));
+    row.push_str(&format!(
+        "            poster_sm: {:?},\n",
+        smp.sample_text.poster_sm
+    ));
+    row.push_str(&format!(
+        "            poster_md: {:?},\n",
+        smp.sample_text.poster_md
+    ));
+    row.push_str(&format!(
+        "            poster_lg: {:?},\n",
+        smp.sample_text.poster_lg
+    ));
+    row.push_str(&format!(
+        "            languages: &{:?},\n",
+        smp.sample_text.languages
+    ));
+    row.push_str("            glyph_groups: &[\n");
+    for grp in smp.glyph_groups.iter() {
+        row.push_str(&format!(
+            "                GlyphGroup {{ name: {:?}, glyphs: {:?} }},\n",
+            grp.name, grp.glyphs
+        ));
+    }
+    row.push_str("            ],\n");
+    row.push_str("        },\n");
+    row.push_str(&format!(
+        "        #[cfg(all(feature = \"{}\", {}))]\n",
+        SAMPLE_TEXT, off
+    ));
+    row.push_str("        sample_text: NO_SAMPLE_TEXT,\n");
+    row.push_str(&format!("        is_noto: {},\n", fam.meta.is_noto));
+    row.push_str(&format!(
+        "        is_brand_font: {},\n",
+        fam.meta.is_brand_font
+    ));
+    row.push_str(&format!(
+        "        is_open_source: {},\n",
+        fam.meta.is_open_source
+    ));
+    row.push_str(&format!(
+        "        color_capabilities: &[{}],\n",
+        fam.clrs
+            .iter()
+            .map(|o| format!("{}::{}", COLOR_CAPABILITY, o.read().unwrap().variant))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    match &fam.stk {
+        Some(stk) => row.push_str(&format!(
+            "        stroke: Some({}::{}),\n",
+            STROKE,
+            stk.read().unwrap().variant
+        )),
+        None => row.push_str("        stroke: None,\n"),
+    }
+    row.push_str(&format!(
+        "        classifications: &[{}],\n",
+        fam.clss
+            .iter()
+            .map(|o| format!("{}::{}", CLASSIFICATION, o.read().unwrap().variant))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    row.push_str(&format!(
+        "        license: {}::{},\n",
+        LICENSE,
+        fam.lic.as_ref().unwrap().read().unwrap().variant
+    ));
+    row.push_str(&format!(
+        "        designers: &[{}],\n",
+        fam.dsgs
+            .iter()
+            .map(|o| format!("{}::{}", DESIGNER, o.read().unwrap().variant))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    row.push_str(&format!(
+        "        subsets: &[{}],\n",
+        fam.subs
+            .iter()
+            .map(|o| format!("{}::{}", SUBSET, o.read().unwrap().variant))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    row.push_str(&format!(
+        "        axes: &[{}],\n",
+        fam.meta
+            .axes
+            .iter()
+            .map(|o| format!(
+                "Axis {{ tag: {:?}, min: {:?}, default: {:?}, max: {:?} }}",
+                o.tag, o.min, o.default_value, o.max
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    row.push_str(&format!("        popularity: {},\n", fam.meta.popularity));
+    row.push_str("    },\n");
+    Ok(row)
+}
+
+pub fn wrt_fle_language(buf: &mut String) {
+    buf.push_str(
+        r#"
+use crate::family::Family;
+use crate::script::Script;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+use strum::IntoEnumIterator;
+
+/// A language written in a script.
+///
+/// Displayed as a language code and an ISO 15924 script code. For example, `ja_Jpan`.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct LanguageTag(pub(crate) &'static str);
+
+impl LanguageTag {
+    /// The tag as a string. For example, `ja_Jpan`.
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// The language code. For example, `ja`.
+    pub fn language(&self) -> &'static str {
+        self.0.split('_').next().unwrap_or_default()
+    }
+
+    /// The ISO 15924 script code. For example, `Jpan`.
+    pub fn script_code(&self) -> &'static str {
+        self.0.rsplit_once('_').map(|o| o.1).unwrap_or_default()
+    }
+
+    /// The [`Script`] of the language.
+    ///
+    /// `None` when no font family is primarily designed for the script.
+    pub fn script(&self) -> Option<Script> {
+        self.script_code().parse().ok()
+    }
+}
+
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Returns families supporting a language.
+///
+/// A language is identified by a language code and a script code.
+/// For example, `ja_Jpan`.
+pub fn families_for_language(lang: &str) -> Vec<Family> {
+    static LANG_FAMS: OnceLock<HashMap<&'static str, Vec<Family>>> = OnceLock::new();
+    LANG_FAMS
+        .get_or_init(|| {
+            let mut ret: HashMap<&'static str, Vec<Family>> = HashMap::new();
+            for fam in Family::iter() {
+                for lang in fam.language_slice() {
+                    ret.entry(lang).or_default().push(fam);
+                }
+            }
+            ret
+        })
+        .get(lang)
+        .cloned()
+        .unwrap_or_default()
+}
+"#,
+    );
+}
+
+pub fn wrt_fle_font(fnts: &[Arc<RwLock<Fnt>>], buf: &mut String) {
+    // Write enum.
+    // pub enum Font {
+    //     ABeeZeeRegular,
+    // }
+    buf.push_str(r#"
+use crate::category::Category;
+use crate::error::{FontError, StringError, UnknownFontError};
+use crate::family::{font_row_idx, Family, FAMILY_ID_INCREMENT};
+use crate::name::{normalize_name, suggestions};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::ops::RangeInclusive;
+use strum::{AsRefStr, Display, EnumCount, EnumIter, EnumString, IntoEnumIterator};
+
+/// An _enumeration_ of [Google fonts](https://fonts.google.com).
+#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
+#[repr(u32)] // for `id`
+pub enum Font {
+"#);
+    for fnt in fnts.iter() {
         buf.push_str(&format!(
             "    /// The [{}](https://fonts.google.com/specimen/{}) _{}_ font.\n",
             fnt.read().unwrap().fam.read().unwrap().name,
@@ -483,7 +1456,7 @@ pub enum Font {
             comma_and(&fnt.read().unwrap().fam.read().unwrap().meta.designers)
         ));
         buf.push_str(&cfg_feature("    ", fnt.read().unwrap().features()));
-        if id == 0 {
+        if fnt.read().unwrap().id == 0 {
             buf.push_str(&format!(
                 "    {} = Family::{} as u32,\n",
                 fnt.read().unwrap().variant,
@@ -493,12 +1466,10 @@ pub enum Font {
             buf.push_str(&format!(
                 "    {} = {} + Family::{} as u32,\n",
                 fnt.read().unwrap().variant,
-                id,
+                fnt.read().unwrap().id,
                 fnt.read().unwrap().fam.read().unwrap().variant
             ));
         }
-
-        fam_prv_o = Some(fnt.read().unwrap().fam.clone());
     }
     buf.push_str("}\n"); // end enum Font
 
@@ -510,14 +1481,10 @@ impl Font {
         *self as u32
     }
 
-    /// Returns the [`Family`].
-    pub fn family(&self) -> Family {
-        Family::from_id((self.id() / ID_INCREMENT) * ID_INCREMENT)
-    }
 
     /// Returns the index of the font file for the [`Family`].
     pub fn font_file_idx(&self) -> usize {
-         (self.id() - self.family().id()) as usize 
+        self.row().file_idx
     }
 
     /// Returns the name of the [`Font`].
@@ -525,6 +1492,56 @@ impl Font {
         self.as_ref().into()
     }    
 
+    /// Returns the [`Font`] with a human readable name or file name.
+    ///
+    /// Ignores case, spaces, hyphens and underscores.
+    /// For example, `roboto bold italic` and `Roboto-VariableFont_wdth,wght.ttf`.
+    ///
+    /// Exact file names are matched first, then display names, as normalized
+    /// file names may be shared. For example, `Roboto_Condensed-BlackItalic.ttf`
+    /// and `RobotoCondensed-BlackItalic.ttf`.
+    pub fn from_name(name: &str) -> Result<Font, FontError> {
+        let key = normalize_name(name);
+        let fle_name = name.trim();
+        // The best match so far, ranked by the name it matched.
+        let mut found: Option<(u8, Font)> = None;
+        for fnt in Font::iter() {
+            let row = fnt.row();
+            if row.file_name.eq_ignore_ascii_case(fle_name) {
+                return Ok(fnt);
+            }
+            if found.is_some_and(|o| o.0 == 1) {
+                continue;
+            }
+            let rnk = if normalize_name(row.display_name) == key {
+                1
+            } else if normalize_name(fnt.as_ref()) == key {
+                2
+            } else if normalize_name(row.file_name) == key {
+                3
+            } else {
+                continue;
+            };
+            if found.is_none_or(|o| rnk < o.0) {
+                found = Some((rnk, fnt));
+            }
+        }
+        found.map(|o| o.1).ok_or_else(|| {
+            FontError::UnknownFont(UnknownFontError::new(
+                name,
+                suggestions(&key, Font::iter().map(|o| o.display_name())),
+            ))
+        })
+    }
+
+    /// The human readable name of the [`Font`].
+    ///
+    /// For example, _Roboto Bold Italic_ and _Roboto Italic Variable_.
+    /// Unique across fonts.
+    pub fn display_name(&self) -> String {
+        self.row().display_name.into()
+    }
+
     /// Indicates whether the [`Font`] uses _variable_ font technology.
     pub fn is_variable(&self) -> bool {
         self.as_ref().contains("Variable")
@@ -543,45 +1560,20 @@ impl Font {
     /// Get TTF font data from the network.
     pub fn get(&self) -> Result<Vec<u8>, FontError> {
         // Get file info from the network.
-        let result = Client::new()
-            .get("https://fonts.google.com/download/list")
-            .query(&[("family", self.family().name())])
-            .send();
-        match result {
-            Err(e) => Err(FontError::Network(e)),
-            Ok(response) => {
-                match response.text() {
+        match self.family().get_file_list() {
+            Err(e) => Err(e),
+            Ok(file_info) => {
+                // Get the file url.
+                let url = &file_info.manifest.file_refs[self.font_file_idx()].url;
+
+                // Get font file from the network.
+                let result = Client::new().get(url).send();
+                match result {
                     Err(e) => Err(FontError::Network(e)),
-                    Ok(txt) => {
-                        // Trim leading excess characters
-                        // to allow deserialization.
-                        //  ")]}'\n{\n
-                        let mut txt: &str = txt.as_ref();
-                        if let Some(idx) = txt.find('{') {
-                            if idx != 0 {
-                                txt = &txt[idx..];
-                            }
-                        }
-
-                        // Deserialize the file info.
-                        match serde_json::from_str::<FamilyFileList>(txt) {
-                            Err(e) => Err(FontError::Deserialize(e)),
-                            Ok(file_info) => {
-                                // Get the file url.
-                                let url = &file_info.manifest.file_refs[self.font_file_idx()].url;
-
-                                // Get font file from the network.
-                                let result = Client::new().get(url).send();
-                                match result {
-                                    Err(e) => Err(FontError::Network(e)),
-                                    Ok(response) => match response.bytes() {
-                                        Err(e) => Err(FontError::Network(e)),
-                                        Ok(bytes) => Ok(bytes.to_vec()),
-                                    },
-                                }
-                            }
-                        }
-                    }
+                    Ok(response) => match response.bytes() {
+                        Err(e) => Err(FontError::Network(e)),
+                        Ok(bytes) => Ok(bytes.to_vec()),
+                    },
                 }
             }
         }
@@ -636,62 +1628,242 @@ impl Font {
 "#,
     );
 
-    // Write `from_id`.
-    buf.push_str("    /// Transforms an _id_ into a [`Font`].\n");
-    buf.push_str("    pub(crate) fn from_id(id: u32) -> Self {\n");
-    buf.push_str("        unsafe { std::mem::transmute(id) }\n");
-    buf.push_str("    }\n"); // end from_id
-
-    // Write `category`.
-    buf.push_str(&format!("    /// Returns the font [`{}`].\n", CATEGORY));
-    buf.push_str(&format!(
-        "    pub fn {}(&self) -> {} {{\n",
-        CATEGORY.to_lowercase(),
-        CATEGORY
-    ));
-    buf.push_str("        match self {\n");
-    for fnt in fnts.iter() {
-        buf.push_str(&cfg_feature("            ", fnt.read().unwrap().features()));
-        buf.push_str(&format!(
-            "            {}::{} => {}::{},\n",
-            FONT,
-            fnt.read().unwrap().variant,
-            CATEGORY,
-            fnt.read()
-                .unwrap()
-                .fam
-                .read()
-                .unwrap()
-                .cat
-                .read()
-                .unwrap()
-                .variant
-        ));
+    // Write accessors.
+    buf.push_str(
+        r#"    /// Returns the catalog row of the [`Font`].
+    fn row(&self) -> &'static FontRow {
+        &FONT_ROWS[font_row_idx(self.id()).expect("every font has a row")]
     }
-    buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end `category`
 
-    buf.push_str("}\n"); // end impl Font
+    /// Returns the [`Family`].
+    pub fn family(&self) -> Family {
+        let id = self.id() - self.id() % FAMILY_ID_INCREMENT;
+        Family::try_from(id).expect("an enabled font has an enabled family")
+    }
 
-    // Write supporting structs.
-    buf.push_str(
-        r#"
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct FamilyFileList {
-    manifest: Manifest,
-}
+    /// Returns the font [`Category`].
+    pub fn category(&self) -> Category {
+        self.family().category()
+    }
+
+    /// Returns vertical [`Metrics`] for the [`Font`].
+    ///
+    /// Available without fetching font data.
+    pub fn metrics(&self) -> Metrics {
+        self.row().metrics
+    }
+
+    /// The original file name of the [`Font`].
+    ///
+    /// For example, `Roboto-VariableFont_wdth,wght.ttf`.
+    pub fn file_name(&self) -> &'static str {
+        self.row().file_name
+    }
+
+    /// Variable font axes listed in the file name of the [`Font`].
+    ///
+    /// For example, `wdth` and `wght`. Empty for a _static_ font.
+    pub fn axes_in_file_name(&self) -> Vec<&'static str> {
+        self.row().axes_in_file_name.to_vec()
+    }
+
+    /// The human readable style name of the [`Font`].
+    ///
+    /// For example, _Bold Italic_.
+    pub fn style_name(&self) -> &'static str {
+        self.row().style_name
+    }
+
+    /// The default weight of the [`Font`].
+    ///
+    /// For example, `400` for a regular font.
+    pub fn weight(&self) -> u16 {
+        self.row().weight
+    }
+
+    /// The weights covered by the [`Font`].
+    ///
+    /// A _variable_ font with a `wght` axis covers the axis range.
+    /// Other fonts cover only their default weight.
+    pub fn weight_range(&self) -> RangeInclusive<u16> {
+        let (min, max) = self.row().weight_range;
+        min..=max
+    }
+
+    /// The stretch percentages covered by the [`Font`].
+    ///
+    /// A _variable_ font with a `wdth` axis covers the axis range.
+    /// Other fonts cover only their default stretch. For example, `100.0`.
+    pub fn stretch_range(&self) -> RangeInclusive<f32> {
+        let (min, max) = self.row().stretch_range;
+        min..=max
+    }
+
+    /// Indicates whether the [`Font`] is italic.
+    pub fn is_italic(&self) -> bool {
+        self.row().italic
+    }
+
+    /// The size of the [`Font`] file in bytes.
+    pub fn file_size(&self) -> u32 {
+        self.row().file_size
+    }
+}
+
+impl TryFrom<u32> for Font {
+    type Error = FontError;
+
+    /// Transforms an _id_ into a [`Font`].
+    ///
+    /// Fails for an unknown _id_, or a font not enabled by crate features.
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        font_row_idx(id)
+            .and_then(|o| FONT_ROWS.get(o))
+            .and_then(|o| o.font)
+            .ok_or_else(|| FontError::InvalidId(StringError::new(&format!("no font with id {}", id))))
+    }
+}
+
+/// Catalog data of a [`Font`].
+///
+/// Every font has a row. `font` is `None` when crate features don't enable it.
+pub(crate) struct FontRow {
+    font: Option<Font>,
+    metrics: Metrics,
+    file_name: &'static str,
+    file_idx: usize,
+    axes_in_file_name: &'static [&'static str],
+    style_name: &'static str,
+    display_name: &'static str,
+    weight: u16,
+    weight_range: (u16, u16),
+    stretch_range: (f32, f32),
+    italic: bool,
+    file_size: u32,
+}
+"#,
+    );
+
+    // Write `FONT_ROWS`.
+    buf.push('\n');
+    buf.push_str(&format!("/// [`{}Row`]s in [`{}`] order.\n", FONT, FONT));
+    buf.push_str(&format!("static FONT_ROWS: &[{}Row] = &[\n", FONT));
+    let dsp_names = display_names(fnts);
+    for fnt in fnts.iter() {
+        let fnt = fnt.read().unwrap();
+        let mtr = fnt.mtr.clone().unwrap();
+        buf.push_str("    FontRow {\n");
+        buf.push_str(&cfg_feature("        ", fnt.features()));
+        buf.push_str(&format!("        font: Some({}::{}),\n", FONT, fnt.variant));
+        buf.push_str(&cfg_not_feature("        ", fnt.features()));
+        buf.push_str("        font: None,\n");
+        buf.push_str(&format!(
+            "        metrics: Metrics {{ line_height: {:?}, units_per_em: {}, ascender: {}, descender: {}, line_gap: {}, cap_height: {:?}, x_height: {:?} }},\n",
+            mtr.line_height,
+            mtr.units_per_em,
+            mtr.ascender,
+            mtr.descender,
+            mtr.line_gap,
+            mtr.cap_height,
+            mtr.x_height,
+        ));
+        buf.push_str(&format!("        file_name: {:?},\n", fnt.file_name()));
+        buf.push_str(&format!("        file_idx: {},\n", fnt.fle_idx));
+        buf.push_str(&format!(
+            "        axes_in_file_name: &{:?},\n",
+            fnt.axes_in_file_name()
+        ));
+        buf.push_str(&format!("        style_name: {:?},\n", fnt.style_name()));
+        buf.push_str(&format!(
+            "        display_name: {:?},\n",
+            dsp_names[&fnt.variant]
+        ));
+        buf.push_str(&format!("        weight: {},\n", mtr.weight));
+        buf.push_str(&format!(
+            "        weight_range: {:?},\n",
+            fnt.weight_range()
+        ));
+        buf.push_str(&format!(
+            "        stretch_range: {:?},\n",
+            fnt.stretch_range()
+        ));
+        buf.push_str(&format!("        italic: {},\n", mtr.italic));
+        buf.push_str(&format!("        file_size: {},\n", mtr.file_size));
+        buf.push_str("    },\n");
+    }
+    buf.push_str("];\n");
+
+    // Write `Metrics`.
+    buf.push_str(
+        r#"
+/// Vertical metrics of a [`Font`].
+///
+/// Values other than `line_height` are in font design units.
+/// Divide by `units_per_em` and multiply by a font size to get a length.
+///
+/// `ascender`, `descender` and `line_gap` are the values used for line layout:
+/// those of the `hhea` table, or of the `OS/2` table's typographic metrics
+/// when the font sets its `USE_TYPO_METRICS` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Metrics {
+    /// The line height as a multiple of the font size.
+    pub line_height: Option<f32>,
+    /// The number of font design units per em.
+    pub units_per_em: u16,
+    /// The ascender.
+    pub ascender: i16,
+    /// The descender. Usually negative.
+    pub descender: i16,
+    /// The line gap.
+    pub line_gap: i16,
+    /// The height of capital letters.
+    pub cap_height: Option<i16>,
+    /// The height of lowercase letters.
+    pub x_height: Option<i16>,
+}
+
+/// The style of a [`Font`] used for font matching.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default, Serialize, Deserialize)]
+pub enum FontStyle {
+    /// An upright style.
+    #[default]
+    Normal,
+    /// An italic style.
+    Italic,
+    /// An oblique style.
+    Oblique,
+}
+"#,
+    );
+
+    // Write supporting structs.
+    buf.push_str(
+        r#"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FamilyFileList {
+    pub(crate) manifest: Manifest,
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Manifest {
-    file_refs: Vec<FileRef>,
+    #[serde(default)]
+    pub(crate) files: Vec<FileContent>,
+    pub(crate) file_refs: Vec<FileRef>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileContent {
+    pub(crate) filename: String,
+    pub(crate) contents: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FileRef {
-    url: String,
+    pub(crate) url: String,
 }
     "#,
     );
@@ -705,8 +1877,9 @@ pub fn wrt_fle_category(cats: &[Arc<RwLock<Cat>>], buf: &mut String) {
     buf.push_str(r#"
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
-use crate::family::Family;
+use crate::family::{families_at, fonts_of, Family};
 use crate::font::Font;
+use std::sync::OnceLock;
 
 /// An _enumeration_ of font categories.
 /// 
@@ -746,80 +1919,31 @@ use crate::font::Font;
     buf.push_str("        }\n");
     buf.push_str("    }\n");
 
-    // Write `families`.
-    buf.push('\n');
-    buf.push_str("    /// Returns families for the [`Category`].\n");
-    buf.push_str(&format!(
-        "    pub fn families(&self) -> Vec<{}> {{\n",
-        FAMILY
-    ));
-    buf.push_str("        match self {\n");
-    for cat in cats.iter() {
-        buf.push_str(&format!(
-            "            {}::{} => {{\n",
-            CATEGORY,
-            cat.read().unwrap().variant
-        ));
-        buf.push_str("                vec![\n");
-        for fam in cat.read().unwrap().fams.iter() {
-            buf.push_str(&cfg_feature(
-                "                    ",
-                fam.read().unwrap().features(),
-            ));
-            buf.push_str(&format!(
-                "                    {}::{},\n",
-                FAMILY,
-                fam.read().unwrap().variant
-            ));
-        }
-        buf.push_str("                ]\n");
-        buf.push_str("            }\n");
-    }
-    buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end `families`
-
-    // Write `fonts`.
-    buf.push('\n');
-    buf.push_str("    /// Returns fonts for the [`Category`].\n");
-    buf.push_str(&format!("    pub fn fonts(&self) -> Vec<{}> {{\n", FONT));
-    buf.push_str("        match self {\n");
-    for cat in cats.iter() {
-        buf.push_str(&format!(
-            "            {}::{} => {{\n",
-            CATEGORY,
-            cat.read().unwrap().variant
-        ));
-        buf.push_str("                vec![\n");
-        for fnt in cat.read().unwrap().fnts.iter() {
-            buf.push_str(&cfg_feature(
-                "                    ",
-                fnt.read().unwrap().features(),
-            ));
-            buf.push_str(&format!(
-                "                    {}::{},\n",
-                FONT,
-                fnt.read().unwrap().variant
-            ));
-        }
-        buf.push_str("                ]\n");
-        buf.push_str("            }\n");
-    }
-    buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end `fonts`
+    // Write `families` and `fonts`.
+    wrt_member_fns(CATEGORY, true, buf);
 
     buf.push_str("}\n"); // end impl Family
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = cats
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(CATEGORY, &fams, buf);
 }
 
-pub fn wrt_fle_subset(subs: &[Arc<RwLock<Sub>>], buf: &mut String) {
+pub fn wrt_fle_subset(subs: &[Arc<RwLock<Sub>>], cli: &Client, buf: &mut String) -> Result<()> {
     // Write enum.
     // pub enum Subset {
     //     Latin,
     // }
     buf.push_str(r#"
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
-use crate::family::Family;
+use std::cmp::Ordering;
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr, IntoEnumIterator};
+use crate::family::{families_at, fonts_of, Family};
 use crate::font::Font;
+use std::sync::OnceLock;
 
 /// An _enumeration_ of font subsets.
 /// 
@@ -840,68 +1964,1590 @@ use crate::font::Font;
     buf.push('\n');
     buf.push_str(&format!("impl {} {{\n", SUBSET));
 
-    // Write `families`.
+    // Write `name`, `ranges`, `scripts`, `is_rtl` and `is_script`.
+    buf.push_str(
+        r#"
+    /// The human readable name of the [`Subset`].
+    ///
+    /// For example, _Latin Extended_.
+    pub fn name(&self) -> String {
+        self.row().name.into()
+    }
+
+    /// Unicode codepoint ranges of the [`Subset`].
+    ///
+    /// The union of the subset's coverage across families.
+    /// Ranges are inclusive, sorted and merged.
+    pub fn ranges(&self) -> &'static [(u32, u32)] {
+        self.row().ranges
+    }
+
+    /// ISO 15924 codes of scripts in the [`Subset`].
+    ///
+    /// For example, `Cyrl`. Empty for a subset without a script, such as _Menu_.
+    pub fn scripts(&self) -> Vec<&'static str> {
+        self.row().scripts.to_vec()
+    }
+
+    /// Indicates whether the [`Subset`] is written right-to-left.
+    pub fn is_rtl(&self) -> bool {
+        self.row().is_rtl
+    }
+
+    /// Indicates whether the [`Subset`] covers a script.
+    ///
+    /// `false` for the _menu_ subset, which holds the glyphs of a family name.
+    pub fn is_script(&self) -> bool {
+        self.row().is_script
+    }
+
+    /// Returns the [`SubsetRow`] of the [`Subset`].
+    fn row(&self) -> &'static SubsetRow {
+        &SUBSET_ROWS[*self as usize]
+    }
+"#,
+    );
+
+    // Write `contains` and `detect`.
+    buf.push_str(
+        r#"
+    /// Indicates whether the [`Subset`] contains a character.
+    pub fn contains(&self, c: char) -> bool {
+        let cp = c as u32;
+        self.ranges()
+            .binary_search_by(|&(fst, lst)| {
+                if lst < cp {
+                    Ordering::Less
+                } else if fst > cp {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Infers the [`Subset`]s a string needs.
+    ///
+    /// Each character is assigned to the smallest subset containing it,
+    /// unless an already chosen subset contains it. Whitespace is skipped.
+    /// The _menu_ subset isn't detected, as it holds the glyphs of a family name
+    /// rather than a script.
+    pub fn detect(txt: &str) -> Vec<Subset> {
+        let mut ret: Vec<Subset> = Vec::new();
+        for c in txt.chars().filter(|o| !o.is_whitespace()) {
+            if ret.iter().any(|o| o.contains(c)) {
+                continue;
+            }
+            let sub = Subset::iter()
+                .filter(|o| o.is_script() && o.contains(c))
+                .min_by_key(|o| o.ranges().iter().map(|(fst, lst)| lst - fst + 1).sum::<u32>());
+            if let Some(sub) = sub {
+                ret.push(sub);
+            }
+        }
+        ret.sort_unstable();
+        ret
+    }
+"#,
+    );
+
+    // Write `families` and `fonts`.
+    wrt_member_fns(SUBSET, true, buf);
+
+    buf.push_str("}\n"); // end impl Family
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = subs
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(SUBSET, &fams, buf);
+
+    // Write `SubsetRow`.
+    buf.push_str(
+        r#"
+/// Data of a [`Subset`].
+struct SubsetRow {
+    name: &'static str,
+    ranges: &'static [(u32, u32)],
+    scripts: &'static [&'static str],
+    is_rtl: bool,
+    is_script: bool,
+}
+"#,
+    );
+
+    // Write `SUBSET_ROWS`.
     buf.push('\n');
-    buf.push_str("    /// Returns families for the [`Subset`].\n");
     buf.push_str(&format!(
-        "    pub fn families(&self) -> Vec<{}> {{\n",
-        FAMILY
+        "/// [`{}Row`]s in [`{}`] order.\n",
+        SUBSET, SUBSET
     ));
-    buf.push_str("        match self {\n");
+    buf.push_str(&format!("static SUBSET_ROWS: &[{}Row] = &[\n", SUBSET));
     for sub in subs.iter() {
+        let sub = sub.read().unwrap();
+        let mut rngs: Vec<(u32, u32)> = Vec::new();
+        for fam in sub.fams.iter() {
+            let dtl = fam.read().unwrap().get_metadata_detail(cli)?;
+            if let Some(txt) = dtl.coverage.get(&sub.name) {
+                rngs.extend(parse_ranges(txt));
+            }
+        }
+        let scrs = sub_scripts(&sub.name);
+        let rtl = scrs.iter().any(|o| RTL_SCRIPTS.contains(&o.as_str()));
+        buf.push_str(&format!("    {}Row {{\n", SUBSET));
+        buf.push_str(&format!("        name: {:?},\n", sub_name(&sub.name)));
+        buf.push_str(&format!("        ranges: &{:?},\n", merge_ranges(rngs)));
+        buf.push_str(&format!("        scripts: &{:?},\n", scrs));
+        buf.push_str(&format!("        is_rtl: {},\n", rtl));
         buf.push_str(&format!(
-            "            {}::{} => {{\n",
-            SUBSET,
-            sub.read().unwrap().variant
+            "        is_script: {},\n",
+            sub.name != MENU_SUBSET
+        ));
+        buf.push_str("    },\n");
+    }
+    buf.push_str("];\n");
+
+    Ok(())
+}
+
+pub fn wrt_fle_script(scrs: &[Arc<RwLock<Scr>>], buf: &mut String) {
+    // Write enum.
+    // pub enum Script {
+    //     Jpan,
+    // }
+    buf.push_str(r#"
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
+use crate::family::{families_at, fonts_of, Family};
+use crate::font::Font;
+
+/// An _enumeration_ of scripts font families are primarily designed for.
+/// 
+/// A variant is named with an [ISO 15924](https://unicode.org/iso15924/) script code.
+#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
+"#);
+    buf.push_str(&format!("pub enum {} {{\n", SCRIPT));
+    for scr in scrs.iter() {
+        buf.push_str(&format!(
+            "    /// The _{}_ script.\n",
+            scr.read().unwrap().name
+        ));
+        buf.push_str(&format!("    {},\n", scr.read().unwrap().variant));
+    }
+    buf.push_str("}\n"); // end enum Script
+
+    // Write impl Script.
+    buf.push('\n');
+    buf.push_str(&format!("impl {} {{\n", SCRIPT));
+
+    // Write `name`.
+    buf.push('\n');
+    buf.push_str("    /// The name of the [`Script`].\n");
+    buf.push_str("    pub fn name(&self) -> String {\n");
+    buf.push_str("        match self {\n");
+    for scr in scrs.iter() {
+        buf.push_str(&format!(
+            "            {}::{} => \"{}\".into(),\n",
+            SCRIPT,
+            scr.read().unwrap().variant,
+            scr.read().unwrap().name
         ));
-        buf.push_str("                vec![\n");
-        for fam in sub.read().unwrap().fams.iter() {
-            buf.push_str(&cfg_feature(
-                "                    ",
-                fam.read().unwrap().features(),
-            ));
-            buf.push_str(&format!(
-                "                    {}::{},\n",
-                FAMILY,
-                fam.read().unwrap().variant
-            ));
-        }
-        buf.push_str("                ]\n");
-        buf.push_str("            }\n");
     }
     buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end `families`
+    buf.push_str("    }\n");
 
-    // Write `fonts`.
+    // Write `code`.
     buf.push('\n');
-    buf.push_str("    /// Returns fonts for the [`Subset`].\n");
-    buf.push_str(&format!("    pub fn fonts(&self) -> Vec<{}> {{\n", FONT));
+    buf.push_str("    /// The ISO 15924 code of the [`Script`].\n");
+    buf.push_str("    pub fn code(&self) -> String {\n");
     buf.push_str("        match self {\n");
-    for sub in subs.iter() {
+    for scr in scrs.iter() {
         buf.push_str(&format!(
-            "            {}::{} => {{\n",
-            SUBSET,
-            sub.read().unwrap().variant
+            "            {}::{} => \"{}\".into(),\n",
+            SCRIPT,
+            scr.read().unwrap().variant,
+            scr.read().unwrap().code
         ));
-        buf.push_str("                vec![\n");
-        for fnt in sub.read().unwrap().fnts.iter() {
-            buf.push_str(&cfg_feature(
-                "                    ",
-                fnt.read().unwrap().features(),
-            ));
-            buf.push_str(&format!(
-                "                    {}::{},\n",
-                FONT,
-                fnt.read().unwrap().variant
-            ));
-        }
-        buf.push_str("                ]\n");
-        buf.push_str("            }\n");
     }
     buf.push_str("        }\n");
-    buf.push_str("    }\n"); // end `fonts`
+    buf.push_str("    }\n");
 
-    buf.push_str("}\n"); // end impl Family
+    // Write `families` and `fonts`.
+    wrt_member_fns(SCRIPT, false, buf);
+
+    buf.push_str("}\n"); // end impl Script
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = scrs
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(SCRIPT, &fams, buf);
+}
+
+pub fn wrt_fle_color(clrs: &[Arc<RwLock<Clr>>], buf: &mut String) {
+    // Write enum.
+    // pub enum ColorCapability {
+    //     COLRv1,
+    // }
+    buf.push_str(r#"
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
+use crate::family::{families_at, fonts_of, Family};
+use crate::font::Font;
+
+/// An _enumeration_ of color font capabilities.
+/// 
+/// A color font has one or more color capabilities.
+#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
+"#);
+    buf.push_str(&format!("pub enum {} {{\n", COLOR_CAPABILITY));
+    for clr in clrs.iter() {
+        buf.push_str(&format!(
+            "    /// The _{}_ color font capability.\n",
+            clr.read().unwrap().name
+        ));
+        buf.push_str(&format!("    {},\n", clr.read().unwrap().variant));
+    }
+    buf.push_str("}\n"); // end enum ColorCapability
+
+    // Write impl ColorCapability.
+    buf.push('\n');
+    buf.push_str(&format!("impl {} {{\n", COLOR_CAPABILITY));
+
+    // Write `name`.
+    buf.push('\n');
+    buf.push_str("    /// The name of the [`ColorCapability`].\n");
+    buf.push_str("    pub fn name(&self) -> String {\n");
+    buf.push_str("        match self {\n");
+    for clr in clrs.iter() {
+        buf.push_str(&format!(
+            "            {}::{} => \"{}\".into(),\n",
+            COLOR_CAPABILITY,
+            clr.read().unwrap().variant,
+            clr.read().unwrap().name
+        ));
+    }
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Write `families` and `fonts`.
+    wrt_member_fns(COLOR_CAPABILITY, false, buf);
+
+    buf.push_str("}\n"); // end impl ColorCapability
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = clrs
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(COLOR_CAPABILITY, &fams, buf);
+}
+
+pub fn wrt_fle_stroke(stks: &[Arc<RwLock<Stk>>], buf: &mut String) {
+    // Write enum.
+    // pub enum Stroke {
+    //     SansSerif,
+    // }
+    buf.push_str(r#"
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
+use crate::family::{families_at, fonts_of, Family};
+use crate::font::Font;
+
+/// An _enumeration_ of font strokes.
+/// 
+/// A font family has zero or one stroke.
+#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
+"#);
+    buf.push_str(&format!("pub enum {} {{\n", STROKE));
+    for stk in stks.iter() {
+        buf.push_str(&format!(
+            "    /// The _{}_ font stroke.\n",
+            stk.read().unwrap().name
+        ));
+        buf.push_str(&format!("    {},\n", stk.read().unwrap().variant));
+    }
+    buf.push_str("}\n"); // end enum Stroke
+
+    // Write impl Stroke.
+    buf.push('\n');
+    buf.push_str(&format!("impl {} {{\n", STROKE));
+
+    // Write `name`.
+    buf.push('\n');
+    buf.push_str("    /// The name of the font [`Stroke`] with spaces.\n");
+    buf.push_str("    pub fn name(&self) -> String {\n");
+    buf.push_str("        match self {\n");
+    for stk in stks.iter() {
+        buf.push_str(&format!(
+            "            {}::{} => \"{}\".into(),\n",
+            STROKE,
+            stk.read().unwrap().variant,
+            stk.read().unwrap().name
+        ));
+    }
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Write `families` and `fonts`.
+    wrt_member_fns(STROKE, false, buf);
+
+    buf.push_str("}\n"); // end impl Stroke
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = stks
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(STROKE, &fams, buf);
+}
+
+pub fn wrt_fle_designer(dsgs: &[Arc<RwLock<Dsg>>], buf: &mut String) {
+    // Write enum.
+    // pub enum Designer {
+    //     ChristianRobertson,
+    // }
+    buf.push_str(r#"
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
+use crate::family::{families_at, fonts_of, Family};
+use crate::font::Font;
+
+/// An _enumeration_ of font designers.
+/// 
+/// A font family has one or more designers.
+#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
+"#);
+    buf.push_str(&format!("pub enum {} {{\n", DESIGNER));
+    for dsg in dsgs.iter() {
+        buf.push_str(&format!(
+            "    /// The font designer _{}_.\n",
+            dsg.read().unwrap().name
+        ));
+        buf.push_str(&format!("    {},\n", dsg.read().unwrap().variant));
+    }
+    buf.push_str("}\n"); // end enum Designer
+
+    // Write impl Designer.
+    buf.push('\n');
+    buf.push_str(&format!("impl {} {{\n", DESIGNER));
+
+    // Write `name`.
+    buf.push('\n');
+    buf.push_str("    /// The name of the font [`Designer`].\n");
+    buf.push_str("    pub fn name(&self) -> String {\n");
+    buf.push_str("        match self {\n");
+    for dsg in dsgs.iter() {
+        buf.push_str(&format!(
+            "            {}::{} => {:?}.into(),\n",
+            DESIGNER,
+            dsg.read().unwrap().variant,
+            dsg.read().unwrap().name
+        ));
+    }
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Write `families` and `fonts`.
+    wrt_member_fns(DESIGNER, false, buf);
+
+    buf.push_str("}\n"); // end impl Designer
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = dsgs
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(DESIGNER, &fams, buf);
+}
+
+pub fn wrt_fle_classification(clss: &[Arc<RwLock<Cls>>], buf: &mut String) {
+    // Write enum.
+    // pub enum Classification {
+    //     Display,
+    // }
+    buf.push_str(r#"
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
+use crate::family::{families_at, fonts_of, Family};
+use crate::font::Font;
+
+/// An _enumeration_ of font classifications.
+/// 
+/// A font family has zero or more classifications.
+#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
+"#);
+    buf.push_str(&format!("pub enum {} {{\n", CLASSIFICATION));
+    for cls in clss.iter() {
+        buf.push_str(&format!(
+            "    /// The _{}_ font classification.\n",
+            cls.read().unwrap().name
+        ));
+        buf.push_str(&format!("    {},\n", cls.read().unwrap().variant));
+    }
+    buf.push_str("}\n"); // end enum Classification
+
+    // Write impl Classification.
+    buf.push('\n');
+    buf.push_str(&format!("impl {} {{\n", CLASSIFICATION));
+
+    // Write `name`.
+    buf.push('\n');
+    buf.push_str("    /// The name of the font [`Classification`].\n");
+    buf.push_str("    pub fn name(&self) -> String {\n");
+    buf.push_str("        match self {\n");
+    for cls in clss.iter() {
+        buf.push_str(&format!(
+            "            {}::{} => \"{}\".into(),\n",
+            CLASSIFICATION,
+            cls.read().unwrap().variant,
+            cls.read().unwrap().name
+        ));
+    }
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Write `families` and `fonts`.
+    wrt_member_fns(CLASSIFICATION, false, buf);
+
+    buf.push_str("}\n"); // end impl Classification
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = clss
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(CLASSIFICATION, &fams, buf);
+}
+
+pub fn wrt_fle_license(lics: &[Arc<RwLock<Lic>>], buf: &mut String) {
+    // Write enum.
+    // pub enum License {
+    //     Ofl,
+    // }
+    buf.push_str(r#"
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIter, EnumString, AsRefStr};
+use crate::family::{families_at, fonts_of, Family};
+use crate::font::Font;
+
+/// An _enumeration_ of font licenses.
+/// 
+/// A font family has one license.
+#[derive(Debug, Display, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumCount, EnumIter, EnumString, AsRefStr)]
+"#);
+    buf.push_str(&format!("pub enum {} {{\n", LICENSE));
+    for lic in lics.iter() {
+        buf.push_str(&format!(
+            "    /// The [{}]({}).\n",
+            lic.read().unwrap().name,
+            lic.read().unwrap().url
+        ));
+        buf.push_str(&format!("    {},\n", lic.read().unwrap().variant));
+    }
+    buf.push_str("}\n"); // end enum License
+
+    // Write impl License.
+    buf.push('\n');
+    buf.push_str(&format!("impl {} {{\n", LICENSE));
+
+    // Write `name`, `url`, `file_name`.
+    let flds = [
+        (
+            "name",
+            "The name of the [`License`].",
+            (|o: &Lic| o.name.clone()) as fn(&Lic) -> String,
+        ),
+        ("url", "The URL of the [`License`].", |o| o.url.clone()),
+        (
+            "file_name",
+            "The name of the [`License`] file distributed with fonts.",
+            |o| o.file_name.clone(),
+        ),
+    ];
+    for (fn_name, doc, fld) in flds.iter() {
+        buf.push('\n');
+        buf.push_str(&format!("    /// {}\n", doc));
+        buf.push_str(&format!("    pub fn {}(&self) -> String {{\n", fn_name));
+        buf.push_str("        match self {\n");
+        for lic in lics.iter() {
+            buf.push_str(&format!(
+                "            {}::{} => \"{}\".into(),\n",
+                LICENSE,
+                lic.read().unwrap().variant,
+                fld(&lic.read().unwrap())
+            ));
+        }
+        buf.push_str("        }\n");
+        buf.push_str("    }\n");
+    }
+
+    // Write `families` and `fonts`.
+    wrt_member_fns(LICENSE, false, buf);
+
+    buf.push_str("}\n"); // end impl License
+
+    // Write `MEMBER_ROWS`.
+    let fams: Vec<_> = lics
+        .iter()
+        .map(|o| o.read().unwrap().fams.clone())
+        .collect();
+    wrt_member_rows(LICENSE, &fams, buf);
+
+    // Write `attribution_report`.
+    buf.push_str(
+        r#"
+/// Returns an attribution report for fonts.
+///
+/// The report lists each font family with its fonts, designers, and license.
+/// Fonts are listed by display name. For example, _ABeeZee Italic_.
+/// Suitable for a `NOTICE` file shipped with a product.
+pub fn attribution_report(fonts: &[Font]) -> String {
+    // Group fonts by family, keeping first-seen order.
+    let mut fams: Vec<(Family, Vec<Font>)> = Vec::new();
+    for font in fonts.iter() {
+        let fam = font.family();
+        match fams.iter_mut().find(|(o, _)| *o == fam) {
+            Some((_, fnts)) => {
+                if !fnts.contains(font) {
+                    fnts.push(*font);
+                }
+            }
+            None => fams.push((fam, vec![*font])),
+        }
+    }
+
+    let mut buf = String::with_capacity(fams.len() * 256);
+    buf.push_str("NOTICE\n\n");
+    buf.push_str("This product includes fonts from Google Fonts (https://fonts.google.com).\n");
+    for (fam, fnts) in fams.iter() {
+        let lic = fam.license();
+        buf.push('\n');
+        buf.push_str(&format!("{}\n", fam.name()));
+        buf.push_str(&format!(
+            "  Fonts: {}\n",
+            fnts.iter().map(|o| o.display_name()).collect::<Vec<_>>().join(", ")
+        ));
+        buf.push_str(&format!(
+            "  Designers: {}\n",
+            fam.designers().iter().map(|o| o.name()).collect::<Vec<_>>().join(", ")
+        ));
+        buf.push_str(&format!("  License: {}\n", lic.name()));
+        buf.push_str(&format!("  License URL: {}\n", lic.url()));
+    }
+    buf
+}
+"#,
+    );
+}
+
+pub fn wrt_fle_sample(buf: &mut String) {
+    buf.push_str(
+        r#"
+/// Sample text for previewing a [`Family`](crate::Family).
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct SampleText {
+    /// A long masthead sample.
+    pub masthead_full: &'static str,
+    /// A short masthead sample.
+    pub masthead_partial: &'static str,
+    /// A sample for previewing font styles.
+    pub styles: &'static str,
+    /// A sample for a type tester.
+    pub tester: &'static str,
+    /// A small poster sample.
+    pub poster_sm: Option<&'static str>,
+    /// A medium poster sample.
+    pub poster_md: Option<&'static str>,
+    /// A large poster sample.
+    pub poster_lg: Option<&'static str>,
+    /// Languages of the sample text. For example, `ja_Jpan`.
+    pub languages: &'static [&'static str],
+    /// Groups of glyphs supported by the font family.
+    pub glyph_groups: &'static [GlyphGroup],
+}
+
+/// A named group of glyphs. For example, _Uppercase_.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct GlyphGroup {
+    /// The name of the glyph group.
+    pub name: &'static str,
+    /// The glyphs in the group.
+    pub glyphs: &'static str,
+}
+"#,
+    );
+}
+
+pub fn wrt_fle_recommend(rngs: &[(u32, u32, String)], buf: &mut String) {
+    buf.push_str(
+        r#"
+use crate::category::Category;
+use crate::family::Family;
+use crate::subset::Subset;
+use std::cmp::Reverse;
+use std::ops::Range;
+use strum::IntoEnumIterator;
+
+/// Recommends font families in a [`Category`] for a text.
+///
+/// The text is segmented into runs of a Unicode script.
+/// Each script maps to the subsets written in it which contain a character of its runs.
+/// Families are ranked by the number of distinct characters they support,
+/// then by being primarily designed for a script of the text,
+/// then by supporting the subsets of the text, then by popularity.
+///
+/// Returns families supporting at least one character, best first.
+pub fn recommend(txt: &str, category: Category) -> Vec<Family> {
+    let runs = segment(txt);
+    let mut scrs: Vec<&str> = runs
+        .iter()
+        .flat_map(|o| std::iter::once(o.0).chain(composites(o.0).iter().copied()))
+        .collect();
+    scrs.sort_unstable();
+    scrs.dedup();
+    let mut subs: Vec<Subset> = Subset::iter()
+        .filter(|sub| {
+            runs.iter().any(|(scr, rng)| {
+                let sub_scrs = sub.scripts();
+                (sub_scrs.contains(scr) || composites(scr).iter().any(|o| sub_scrs.contains(o)))
+                    && txt[rng.clone()].chars().any(|c| sub.contains(c))
+            })
+        })
+        .collect();
+    subs.sort_unstable();
+    subs.dedup();
+    let mut chrs: Vec<char> = txt.chars().filter(|o| !o.is_whitespace()).collect();
+    chrs.sort_unstable();
+    chrs.dedup();
+
+    let mut rnks: Vec<(Family, usize, bool, usize)> = category
+        .family_slice()
+        .iter()
+        .map(|&fam| {
+            let cvr = chrs.iter().filter(|o| fam.supports_char(**o)).count();
+            let prm = fam
+                .primary_script()
+                .is_some_and(|o| scrs.contains(&o.code().as_str()));
+            let fam_subs = fam.subset_slice();
+            let sub_cnt = subs.iter().filter(|o| fam_subs.contains(o)).count();
+            (fam, cvr, prm, sub_cnt)
+        })
+        .filter(|o| o.1 > 0)
+        .collect();
+    rnks.sort_by_key(|o| (Reverse(o.1), Reverse(o.2), Reverse(o.3), o.0.popularity()));
+    rnks.into_iter().map(|o| o.0).collect()
+}
+
+/// Segments a text into runs of a Unicode script.
+///
+/// Each run has an ISO 15924 script code and a byte range of the text.
+/// Characters of the _Common_ and _Inherited_ scripts, such as whitespace,
+/// punctuation and combining marks, stay in the current run.
+/// Leading ones join the first run, so runs cover the text from its start.
+/// A text without a script character has no runs.
+pub(crate) fn segment(txt: &str) -> Vec<(&'static str, Range<usize>)> {
+    let mut runs: Vec<(&'static str, Range<usize>)> = Vec::new();
+    for (idx, c) in txt.char_indices() {
+        let end = idx + c.len_utf8();
+        match (script(c), runs.last_mut()) {
+            (Some(scr), Some(run)) if run.0 == scr => run.1.end = end,
+            (Some(scr), None) => runs.push((scr, 0..end)),
+            (Some(scr), Some(_)) => runs.push((scr, idx..end)),
+            (None, Some(run)) => run.1.end = end,
+            (None, None) => {}
+        }
+    }
+    runs
+}
+
+/// Returns the ISO 15924 code of a character's Unicode script.
+///
+/// Returns `None` for the _Common_, _Inherited_ and _Unknown_ scripts.
+fn script(c: char) -> Option<&'static str> {
+    let c = c as u32;
+    let idx = SCRIPT_RANGES.partition_point(|o| o.1 < c);
+    SCRIPT_RANGES
+        .get(idx)
+        .filter(|o| o.0 <= c)
+        .map(|o| o.2)
+}
+
+/// Returns ISO 15924 codes of composite scripts written with a script.
+///
+/// For example, Hiragana is written in Japanese text.
+fn composites(code: &str) -> &'static [&'static str] {
+    match code {
+        "Bopo" => &["Hant"],
+        "Hang" => &["Kore"],
+        "Hani" => &["Hans", "Hant", "Jpan", "Kore"],
+        "Hira" | "Kana" => &["Jpan"],
+        _ => &[],
+    }
+}
+"#,
+    );
+
+    // Write the script range table.
+    // static SCRIPT_RANGES: &[(u32, u32, &str)] = &[
+    //     (65, 90, "Latn"),
+    // ];
+    buf.push('\n');
+    buf.push_str("/// Unicode script ranges with ISO 15924 codes, sorted by codepoint.\n");
+    buf.push_str("static SCRIPT_RANGES: &[(u32, u32, &str)] = &[\n");
+    for (fst, lst, code) in rngs.iter() {
+        buf.push_str(&format!("    ({}, {}, \"{}\"),\n", fst, lst, code));
+    }
+    buf.push_str("];\n");
+}
+
+pub fn wrt_fle_search(fams: &[Arc<RwLock<Fam>>], cli: &Client, buf: &mut String) -> Result<()> {
+    buf.push_str(
+        r#"
+use crate::family::{family_at, Family};
+use crate::name::edit_distance;
+
+/// A field of a [`Family`] in the search index.
+///
+/// Fields in use depend on enabled features.
+#[derive(Debug, Clone, Copy)]
+#[allow(dead_code)]
+enum Field {
+    Name,
+    Designer,
+    Classification,
+    Language,
+    Description,
+}
+
+impl Field {
+    /// The relative weight of a match in the field.
+    fn weight(self) -> f32 {
+        match self {
+            Field::Name => 8.0,
+            Field::Designer => 4.0,
+            Field::Classification => 3.0,
+            Field::Language => 2.0,
+            Field::Description => 1.0,
+        }
+    }
+}
+
+/// Searches font families with a fuzzy match of each query word.
+///
+/// Matches names, designers, classifications and languages.
+/// Matches descriptions with the `descriptions` feature.
+///
+/// Returns matching families with a score, highest first.
+/// Scores are weighted by popularity.
+pub fn search(query: &str) -> Vec<(Family, f32)> {
+    let wrds: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|o| !o.is_empty())
+        .map(|o| o.to_lowercase())
+        .collect();
+    let mut ret = Vec::new();
+    if wrds.is_empty() {
+        return ret;
+    }
+    for (row, terms) in INDEX.iter().enumerate() {
+        let Some(fam) = family_at(row) else {
+            continue;
+        };
+        let score: f32 = wrds
+            .iter()
+            .map(|wrd| {
+                terms
+                    .iter()
+                    .map(|(term, fld)| term_score(wrd, term) * fld.weight())
+                    .fold(0.0, f32::max)
+            })
+            .sum();
+        if score > 0.0 {
+            let pop = 1.0 + 100.0 / (100.0 + fam.popularity() as f32);
+            ret.push((fam, score * pop));
+        }
+    }
+    ret.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ret
+}
+
+/// Scores how closely a query word matches an index term, from `0.0` to `1.0`.
+fn term_score(wrd: &str, term: &str) -> f32 {
+    let max_dst = wrd.len() / 4;
+    if wrd == term {
+        1.0
+    } else if term.starts_with(wrd) {
+        0.75
+    } else if max_dst > 0
+        && wrd.len().abs_diff(term.len()) <= max_dst
+        && edit_distance(wrd, term) <= max_dst
+    {
+        0.5
+    } else {
+        0.0
+    }
+}
+"#,
+    );
+
+    // Write index.
+    // Terms are lowercase words.
+    buf.push('\n');
+    buf.push_str("/// Search terms by [`FamilyRow`](crate::family::FamilyRow) index.\n");
+    buf.push_str("static INDEX: &[&[(&str, Field)]] = &[\n");
+    for fam in fams.iter() {
+        let dtl = fam.read().unwrap().get_metadata_detail(cli)?;
+        let mut terms: Vec<(String, &str)> = Vec::new();
+        let name = &fam.read().unwrap().name;
+        terms.push((name.to_lowercase().replace(' ', ""), "Name"));
+        for wrd in search_words(name) {
+            terms.push((wrd, "Name"));
+        }
+        for dsg in fam.read().unwrap().meta.designers.iter() {
+            for wrd in search_words(dsg) {
+                terms.push((wrd, "Designer"));
+            }
+        }
+        for cls in fam.read().unwrap().meta.classifications.iter() {
+            for wrd in search_words(cls) {
+                terms.push((wrd, "Classification"));
+            }
+        }
+        for lang in dtl.languages.iter() {
+            let scr = lang.split('_').nth(1).unwrap_or_default();
+            for wrd in search_words(lang)
+                .into_iter()
+                .chain(search_words(&scr_name(scr)))
+            {
+                terms.push((wrd, "Language"));
+            }
+        }
+        let dsc = Html::parse_fragment(&unescaper::unescape(&dtl.description).unwrap())
+            .root_element()
+            .text()
+            .collect::<Vec<_>>()
+            .join(" ");
+        for wrd in search_words(&dsc) {
+            if wrd.len() > 2 && !STOP_WORDS.contains(&wrd.as_str()) {
+                terms.push((wrd, "Description"));
+            }
+        }
+        terms.sort_unstable();
+        terms.dedup();
+
+        buf.push_str("    &[\n");
+        for (term, fld) in terms {
+            if fld == "Description" {
+                buf.push_str(&format!("        #[cfg(feature = \"{}\")]\n", DESCRIPTIONS));
+            }
+            buf.push_str(&format!("        ({:?}, Field::{}),\n", term, fld));
+        }
+        buf.push_str("    ],\n");
+    }
+    buf.push_str("];\n");
+
+    Ok(())
+}
+
+pub fn wrt_fle_codepoint(buf: &mut String) {
+    buf.push_str(
+        r#"
+use crate::family::Family;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use strum::IntoEnumIterator;
+
+/// Returns the [`Family`]s which support a character.
+///
+/// Binary searches an index of codepoint segments.
+pub fn families_for_char(c: char) -> Vec<Family> {
+    let cp = c as u32;
+    let idx = index();
+    let found = idx.segments.binary_search_by(|&(fst, lst, _)| {
+        if lst < cp {
+            Ordering::Less
+        } else if fst > cp {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+    let mut ret = Vec::new();
+    if let Ok(sgm_idx) = found {
+        for (wrd_idx, wrd) in idx.sets[idx.segments[sgm_idx].2].iter().enumerate() {
+            for bit in 0..64 {
+                if wrd & (1 << bit) != 0 {
+                    ret.push(idx.families[wrd_idx * 64 + bit]);
+                }
+            }
+        }
+    }
+    ret
+}
+
+/// Codepoint segments where the set of families doesn't change.
+struct Index {
+    /// Families enabled by crate features.
+    families: Vec<Family>,
+    /// Bit sets of indexes into `families`.
+    sets: Vec<Vec<u64>>,
+    /// Sorted, disjoint codepoint segments with an index into `sets`.
+    segments: Vec<(u32, u32, usize)>,
+}
+
+/// Returns the [`Index`], built on first use from the codepoint ranges of enabled families.
+fn index() -> &'static Index {
+    static INDEX: OnceLock<Index> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let families: Vec<Family> = Family::iter().collect();
+        let mut evts: Vec<(u32, bool, usize)> = Vec::new();
+        for (idx, fam) in families.iter().enumerate() {
+            for &(fst, lst) in fam.codepoint_ranges() {
+                evts.push((fst, true, idx));
+                evts.push((lst + 1, false, idx));
+            }
+        }
+        evts.sort_unstable();
+        let mut set = vec![0u64; families.len().div_ceil(64)];
+        let mut sets: Vec<Vec<u64>> = Vec::new();
+        let mut set_idxs: HashMap<Vec<u64>, usize> = HashMap::new();
+        let mut segments: Vec<(u32, u32, usize)> = Vec::new();
+        let mut evt_idx = 0;
+        while evt_idx < evts.len() {
+            let pos = evts[evt_idx].0;
+            while evt_idx < evts.len() && evts[evt_idx].0 == pos {
+                let (_, add, idx) = evts[evt_idx];
+                if add {
+                    set[idx / 64] |= 1 << (idx % 64);
+                } else {
+                    set[idx / 64] &= !(1 << (idx % 64));
+                }
+                evt_idx += 1;
+            }
+            if evt_idx == evts.len() || set.iter().all(|o| *o == 0) {
+                continue;
+            }
+            let end = evts[evt_idx].0 - 1;
+            let set_idx = *set_idxs.entry(set.clone()).or_insert_with(|| {
+                sets.push(set.clone());
+                sets.len() - 1
+            });
+            match segments.last_mut() {
+                Some(prv) if prv.1 + 1 == pos && prv.2 == set_idx => prv.1 = end,
+                _ => segments.push((pos, end, set_idx)),
+            }
+        }
+        Index {
+            families,
+            sets,
+            segments,
+        }
+    })
+}
+"#,
+    );
+}
+
+pub fn wrt_fle_cover(buf: &mut String) {
+    buf.push_str(
+        r#"
+use crate::font::Font;
+use crate::query::FontQuery;
+use crate::subset::Subset;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// The cost minimized by [`minimal_cover`].
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+pub enum CoverCost {
+    /// The number of font files.
+    #[default]
+    FileCount,
+    /// The total size of font files in bytes.
+    FileSize,
+}
+
+/// Returns few fonts which together support every [`Subset`].
+///
+/// Fonts are chosen from those matching `constraints`.
+/// For example, restrict fonts to a [`Category`](crate::Category) or to _variable_ fonts.
+/// At most one font is chosen per family.
+///
+/// Uses a greedy set cover, which is within a small factor of the true minimum.
+/// Subsets no matching font supports are left uncovered.
+pub fn minimal_cover(subsets: &[Subset], constraints: &FontQuery, cost: CoverCost) -> Vec<Font> {
+    let mut rest: Vec<Subset> = subsets.to_vec();
+    rest.sort_unstable();
+    rest.dedup();
+    let mut cnds: Vec<(Font, &'static [Subset])> = candidates(constraints, cost)
+        .into_iter()
+        .map(|o| (o, o.family().subset_slice()))
+        .collect();
+    let mut ret = Vec::new();
+    while !rest.is_empty() {
+        let gain = |subs: &&[Subset]| rest.iter().filter(|o| subs.contains(o)).count() as u64;
+        match pick(&cnds, cost, gain) {
+            None => break,
+            Some(idx) => {
+                let (fnt, subs) = cnds.swap_remove(idx);
+                rest.retain(|o| !subs.contains(o));
+                ret.push(fnt);
+            }
+        }
+    }
+    ret
+}
+
+/// Returns few fonts which together support every codepoint in the ranges.
+///
+/// Fonts are chosen as with [`minimal_cover`].
+/// Codepoints no matching font supports are left uncovered.
+pub fn minimal_cover_ranges(
+    ranges: &[RangeInclusive<u32>],
+    constraints: &FontQuery,
+    cost: CoverCost,
+) -> Vec<Font> {
+    let mut rest: Vec<(u32, u32)> = ranges
+        .iter()
+        .filter(|o| !o.is_empty())
+        .map(|o| (*o.start(), *o.end()))
+        .collect();
+    let mut cnds: Vec<(Font, &'static [(u32, u32)])> = candidates(constraints, cost)
+        .into_iter()
+        .map(|o| (o, o.family().codepoint_ranges()))
+        .collect();
+    let mut ret = Vec::new();
+    while !rest.is_empty() {
+        let gain = |rngs: &&[(u32, u32)]| overlap(&rest, rngs);
+        match pick(&cnds, cost, gain) {
+            None => break,
+            Some(idx) => {
+                let (fnt, rngs) = cnds.swap_remove(idx);
+                rest = subtract(&rest, rngs);
+                ret.push(fnt);
+            }
+        }
+    }
+    ret
+}
+
+/// Returns the cheapest matching font of each family.
+fn candidates(constraints: &FontQuery, cost: CoverCost) -> Vec<Font> {
+    let mut fams: BTreeMap<_, Font> = BTreeMap::new();
+    for fnt in constraints.fonts() {
+        let fam = fams.entry(fnt.family()).or_insert(fnt);
+        if cost == CoverCost::FileSize && fnt.file_size() < fam.file_size() {
+            *fam = fnt;
+        }
+    }
+    fams.into_values().collect()
+}
+
+/// Returns the index of the candidate with the best gain for its cost.
+///
+/// Ties prefer smaller files. Returns `None` when no candidate gains.
+fn pick<T>(cnds: &[(Font, T)], cost: CoverCost, gain: impl Fn(&T) -> u64) -> Option<usize> {
+    let mut best: Option<(usize, u64, u64)> = None;
+    for (idx, (fnt, cvr)) in cnds.iter().enumerate() {
+        let gan = gain(cvr);
+        if gan == 0 {
+            continue;
+        }
+        let cst = match cost {
+            CoverCost::FileCount => 1,
+            CoverCost::FileSize => u64::from(fnt.file_size()).max(1),
+        };
+        let better = match best {
+            None => true,
+            // Compare gain / cost without division.
+            Some((bst_idx, bst_gan, bst_cst)) => match (gan * bst_cst).cmp(&(bst_gan * cst)) {
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Equal => fnt.file_size() < cnds[bst_idx].0.file_size(),
+            },
+        };
+        if better {
+            best = Some((idx, gan, cst));
+        }
+    }
+    best.map(|o| o.0)
+}
+
+/// Counts codepoints in both sets of ranges.
+fn overlap(a: &[(u32, u32)], b: &[(u32, u32)]) -> u64 {
+    let mut cnt = 0;
+    for &(a_fst, a_lst) in a {
+        for &(b_fst, b_lst) in b {
+            let fst = a_fst.max(b_fst);
+            let lst = a_lst.min(b_lst);
+            if fst <= lst {
+                cnt += u64::from(lst - fst) + 1;
+            }
+        }
+    }
+    cnt
+}
+
+/// Removes codepoints of `b` from `a`.
+fn subtract(a: &[(u32, u32)], b: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut ret = a.to_vec();
+    for &(b_fst, b_lst) in b {
+        ret = ret
+            .into_iter()
+            .flat_map(|(fst, lst)| {
+                let mut prts = Vec::with_capacity(2);
+                if lst < b_fst || fst > b_lst {
+                    prts.push((fst, lst));
+                } else {
+                    if fst < b_fst {
+                        prts.push((fst, b_fst - 1));
+                    }
+                    if lst > b_lst {
+                        prts.push((b_lst + 1, lst));
+                    }
+                }
+                prts
+            })
+            .collect();
+    }
+    ret
+}
+"#,
+    );
+}
+
+pub fn wrt_fle_fallback(buf: &mut String) {
+    buf.push_str(
+        r#"
+use crate::codepoint::families_for_char;
+use crate::family::Family;
+use std::cmp::Reverse;
+use std::ops::Range;
+
+/// A run of text assigned to a [`Family`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct TextRun {
+    /// The family which renders the run.
+    pub family: Family,
+    /// The byte range of the run in the text.
+    pub range: Range<usize>,
+}
+
+/// An ordered list of font families which covers a text.
+///
+/// The preferred family comes first. Fallback families follow in order of first use.
+/// A fallback family is chosen from the same [`Category`](crate::Category) as the
+/// preferred family when possible, then from Noto families, then by popularity.
+///
+/// ```rust
+/// use google_fonts::{FallbackChain, Family};
+///
+/// let txt = "Hello, мир!";
+/// let chn = FallbackChain::new(Family::Roboto, txt);
+/// for run in chn.runs() {
+///     println!("{}: {}", run.family, &txt[run.range.clone()]);
+/// }
+/// ```
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct FallbackChain {
+    families: Vec<Family>,
+    runs: Vec<TextRun>,
+    missing: Vec<char>,
+}
+
+impl FallbackChain {
+    /// Assigns each character of a text to a family, starting with the preferred family.
+    pub fn new(preferred: Family, txt: &str) -> Self {
+        let mut chn = FallbackChain {
+            families: vec![preferred],
+            runs: Vec::new(),
+            missing: Vec::new(),
+        };
+        for (idx, c) in txt.char_indices() {
+            let end = idx + c.len_utf8();
+
+            // Extend the current run when possible.
+            // Whitespace stays in the current run to keep runs whole.
+            if let Some(run) = chn.runs.last_mut() {
+                if c.is_whitespace() || run.family.supports_char(c) {
+                    run.range.end = end;
+                    continue;
+                }
+            }
+
+            // Use a family already in the chain, then look for a new fallback.
+            let fam = match chn.families.iter().find(|o| o.supports_char(c)) {
+                Some(fam) => Some(*fam),
+                None => {
+                    let gap = gap_chars(&chn.families, &txt[idx..]);
+                    let fam = fallback(preferred, c, &gap);
+                    if let Some(fam) = fam {
+                        chn.families.push(fam);
+                    }
+                    fam
+                }
+            };
+            match (fam, chn.runs.last_mut()) {
+                (Some(fam), _) => chn.runs.push(TextRun {
+                    family: fam,
+                    range: idx..end,
+                }),
+                (None, Some(run)) => run.range.end = end,
+                (None, None) => chn.runs.push(TextRun {
+                    family: preferred,
+                    range: idx..end,
+                }),
+            }
+            if fam.is_none() && !chn.missing.contains(&c) {
+                chn.missing.push(c);
+            }
+        }
+        chn
+    }
+
+    /// Families in the chain, in order of preference.
+    pub fn families(&self) -> &[Family] {
+        &self.families
+    }
+
+    /// Runs of the text, in text order.
+    ///
+    /// Adjacent runs have different families.
+    pub fn runs(&self) -> &[TextRun] {
+        &self.runs
+    }
+
+    /// Characters no family supports.
+    ///
+    /// These characters are left in the neighboring run.
+    pub fn missing_chars(&self) -> &[char] {
+        &self.missing
+    }
+}
+
+/// Returns the distinct characters of a gap no family in the chain supports.
+///
+/// The gap starts the text and ends before the next character the chain supports.
+/// Whitespace doesn't end the gap.
+fn gap_chars(families: &[Family], txt: &str) -> Vec<char> {
+    let mut ret: Vec<char> = txt
+        .chars()
+        .take_while(|c| c.is_whitespace() || !families.iter().any(|o| o.supports_char(*c)))
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    ret.sort_unstable();
+    ret.dedup();
+    ret
+}
+
+/// Returns the best fallback family for a character.
+///
+/// Prefers the category of the preferred family, then Noto families,
+/// then families covering more of the gap, then popular families.
+fn fallback(preferred: Family, c: char, gap: &[char]) -> Option<Family> {
+    let cat = preferred.category();
+    families_for_char(c).into_iter().min_by_key(|o| {
+        (
+            o.category() != cat,
+            !o.is_noto(),
+            Reverse(gap.iter().filter(|c| o.supports_char(**c)).count()),
+            o.popularity(),
+        )
+    })
+}
+"#,
+    );
+}
+
+pub fn wrt_fle_name(buf: &mut String) {
+    buf.push_str(
+        r#"
+/// The maximum number of suggestions for an unknown name.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Normalizes a font name for comparison.
+///
+/// Lowercases, drops a font file extension, and keeps only letters and digits.
+pub(crate) fn normalize_name(name: &str) -> String {
+    let lower = name.trim().to_lowercase();
+    let stem = lower
+        .strip_suffix(".ttf")
+        .or_else(|| lower.strip_suffix(".otf"))
+        .unwrap_or(&lower);
+    stem.chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+/// Returns the names closest to a normalized key, closest first.
+///
+/// Names further than a third of the key length, or two edits for short keys, are skipped.
+pub(crate) fn suggestions(key: &str, names: impl Iterator<Item = String>) -> Vec<String> {
+    let max_dst = (key.chars().count() / 3).max(2);
+    let mut dsts: Vec<(usize, String)> = names
+        .map(|o| (edit_distance(key, &normalize_name(&o)), o))
+        .filter(|o| o.0 <= max_dst)
+        .collect();
+    dsts.sort();
+    dsts.dedup_by(|a, b| a.1 == b.1);
+    dsts.into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|o| o.1)
+        .collect()
+}
+
+/// Returns the Levenshtein distance between two strings.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prv: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let sub = prv[j] + usize::from(ca != *cb);
+            cur[j + 1] = sub.min(prv[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prv, &mut cur);
+    }
+    prv[b.len()]
+}
+"#,
+    );
+}
+
+pub fn wrt_fle_query(buf: &mut String) {
+    buf.push_str(
+        r#"
+use crate::category::Category;
+use crate::color::ColorCapability;
+use crate::family::Family;
+use crate::font::Font;
+use crate::subset::Subset;
+use std::ops::RangeInclusive;
+use strum::IntoEnumIterator;
+
+/// A builder for filtering [`Family`]s and [`Font`]s.
+///
+/// Filters combine with _and_. An unset filter matches everything.
+///
+/// ```rust
+/// use google_fonts::{Category, FontQuery, Subset};
+///
+/// // A variable serif that supports Cyrillic with weights 300-800.
+/// let fonts: Vec<_> = FontQuery::new()
+///     .category(Category::Serif)
+///     .subset(Subset::Cyrillic)
+///     .variable(true)
+///     .weight(300..=800)
+///     .fonts()
+///     .collect();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FontQuery {
+    categories: Vec<Category>,
+    subsets: Vec<Subset>,
+    languages: Vec<String>,
+    popularity: Option<u32>,
+    noto: Option<bool>,
+    brand_font: Option<bool>,
+    open_source: Option<bool>,
+    color_capabilities: Vec<ColorCapability>,
+    variable: Option<bool>,
+    weight: Option<RangeInclusive<u16>>,
+    italic: Option<bool>,
+    axes: Vec<String>,
+}
+
+impl FontQuery {
+    /// Returns a query which matches every font.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches families in the [`Category`].
+    ///
+    /// Calling more than once matches families in any of the categories.
+    pub fn category(mut self, cat: Category) -> Self {
+        self.categories.push(cat);
+        self
+    }
+
+    /// Matches families which support the [`Subset`].
+    ///
+    /// Calling more than once matches families which support all of the subsets.
+    pub fn subset(mut self, sub: Subset) -> Self {
+        self.subsets.push(sub);
+        self
+    }
+
+    /// Matches families which support the language. For example, `ja_Jpan`.
+    ///
+    /// Calling more than once matches families which support all of the languages.
+    pub fn language(mut self, lang: &str) -> Self {
+        self.languages.push(lang.into());
+        self
+    }
+
+    /// Matches families ranked within the `max_rank` most popular.
+    pub fn popularity(mut self, max_rank: u32) -> Self {
+        self.popularity = Some(max_rank);
+        self
+    }
+
+    /// Matches [Noto](https://fonts.google.com/noto) families when `true`, and other families when `false`.
+    pub fn noto(mut self, noto: bool) -> Self {
+        self.noto = Some(noto);
+        self
+    }
+
+    /// Matches brand font families when `true`, and other families when `false`.
+    pub fn brand_font(mut self, brand_font: bool) -> Self {
+        self.brand_font = Some(brand_font);
+        self
+    }
+
+    /// Matches open source families when `true`, and other families when `false`.
+    pub fn open_source(mut self, open_source: bool) -> Self {
+        self.open_source = Some(open_source);
+        self
+    }
+
+    /// Matches families with the [`ColorCapability`].
+    ///
+    /// Calling more than once matches families with all of the capabilities.
+    pub fn color_capability(mut self, clr: ColorCapability) -> Self {
+        self.color_capabilities.push(clr);
+        self
+    }
+
+    /// Matches _variable_ fonts when `true`, and _static_ fonts when `false`.
+    pub fn variable(mut self, variable: bool) -> Self {
+        self.variable = Some(variable);
+        self
+    }
+
+    /// Matches fonts covering the weights.
+    ///
+    /// A _variable_ font matches when its weight range spans `weight`.
+    /// A _static_ font matches when its weight is within `weight`.
+    pub fn weight(mut self, weight: RangeInclusive<u16>) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Matches italic fonts when `true`, and upright fonts when `false`.
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = Some(italic);
+        self
+    }
+
+    /// Matches _variable_ fonts with the axis. For example, `wdth`.
+    ///
+    /// Calling more than once matches fonts with all of the axes.
+    pub fn axis(mut self, tag: &str) -> Self {
+        self.axes.push(tag.into());
+        self
+    }
+
+    /// Returns an iterator of matching [`Family`]s.
+    ///
+    /// A family matches when at least one of its fonts matches.
+    pub fn families(&self) -> impl Iterator<Item = Family> + '_ {
+        Family::iter().filter(move |fam| {
+            self.matches_family(fam)
+                && (!self.has_font_filters() || fam.font_slice().iter().any(|o| self.matches_font(o)))
+        })
+    }
+
+    /// Returns an iterator of matching [`Font`]s.
+    pub fn fonts(&self) -> impl Iterator<Item = Font> + '_ {
+        Family::iter()
+            .filter(move |fam| self.matches_family(fam))
+            .flat_map(|fam| fam.font_slice().iter().copied())
+            .filter(move |fnt| self.matches_font(fnt))
+    }
+
+    fn has_font_filters(&self) -> bool {
+        self.variable.is_some()
+            || self.weight.is_some()
+            || self.italic.is_some()
+            || !self.axes.is_empty()
+    }
+
+    fn matches_family(&self, fam: &Family) -> bool {
+        if !self.categories.is_empty() && !self.categories.contains(&fam.category()) {
+            return false;
+        }
+        if let Some(max_rank) = self.popularity {
+            if fam.popularity() > max_rank {
+                return false;
+            }
+        }
+        let flgs = [
+            (self.noto, fam.is_noto()),
+            (self.brand_font, fam.is_brand_font()),
+            (self.open_source, fam.is_open_source()),
+        ];
+        if flgs.iter().any(|(want, has)| want.is_some_and(|o| o != *has)) {
+            return false;
+        }
+        if !self.color_capabilities.is_empty() {
+            let clrs = fam.color_capability_slice();
+            if !self.color_capabilities.iter().all(|o| clrs.contains(o)) {
+                return false;
+            }
+        }
+        if !self.subsets.is_empty() {
+            let subs = fam.subset_slice();
+            if !self.subsets.iter().all(|o| subs.contains(o)) {
+                return false;
+            }
+        }
+        if !self.languages.is_empty() {
+            let langs = fam.language_slice();
+            if !self.languages.iter().all(|o| langs.contains(&o.as_str())) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn matches_font(&self, fnt: &Font) -> bool {
+        if let Some(variable) = self.variable {
+            if fnt.is_variable() != variable {
+                return false;
+            }
+        }
+        if let Some(italic) = self.italic {
+            if fnt.is_italic() != italic {
+                return false;
+            }
+        }
+        if let Some(weight) = &self.weight {
+            let rng = fnt.weight_range();
+            let matched = if fnt.is_variable() {
+                rng.start() <= weight.start() && weight.end() <= rng.end()
+            } else {
+                weight.contains(&fnt.weight())
+            };
+            if !matched {
+                return false;
+            }
+        }
+        if !self.axes.is_empty() {
+            let axes = fnt.axes_in_file_name();
+            if !self.axes.iter().all(|o| axes.contains(&o.as_str())) {
+                return false;
+            }
+        }
+        true
+    }
+}
+"#,
+    );
 }
 
 pub fn wrt_fle_error(buf: &mut String) {
@@ -923,6 +3569,9 @@ use std::fmt::{Display, Formatter, Result};
 /// - `Deserialize`: Indicates an error that occurred while deserializing JSON data.
 /// - `CacheDir`: Indicates an error that occurred while interacting with the cache directory.
 /// - `CacheFile`: Indicates an error that occurred while interacting with a cache file.
+/// - `MissingFile`: Indicates a file missing from a font family's file list.
+/// - `UnknownFont`: Indicates a name which doesn't match a font family or font.
+/// - `InvalidId`: Indicates an _id_ which doesn't match an enabled font family or font.
 #[derive(Debug)]
 pub enum FontError {
     /// An error that occurred while making a network request.
@@ -984,6 +3633,50 @@ pub enum FontError {
     /// }
     /// ```
     CacheFile(std::io::Error),
+
+    /// A file missing from a font family's file list.
+    ///
+    /// This variant wraps a `StringError`, which names the missing file
+    /// and the font family.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use google_fonts::FontError;
+    /// if let FontError::MissingFile(e) = error {
+    ///     println!("Missing file error: {}", e);
+    /// }
+    /// ```
+    MissingFile(StringError),
+
+    /// A name which doesn't match a font family or font.
+    ///
+    /// This variant wraps an `UnknownFontError`, which holds the name
+    /// and similar names from the catalog.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use google_fonts::FontError;
+    /// if let FontError::UnknownFont(e) = error {
+    ///     println!("Did you mean: {:?}", e.suggestions());
+    /// }
+    /// ```
+    UnknownFont(UnknownFontError),
+
+    /// An _id_ which doesn't match an enabled font family or font.
+    ///
+    /// This variant wraps a `StringError`, which names the _id_.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use google_fonts::FontError;
+    /// if let FontError::InvalidId(e) = error {
+    ///     println!("Invalid id error: {}", e);
+    /// }
+    /// ```
+    InvalidId(StringError),
 }
 
 impl Display for FontError {
@@ -993,6 +3686,9 @@ impl Display for FontError {
             FontError::Deserialize(e) => write!(f, "deserialization error: {}", e),
             FontError::CacheDir(e) => write!(f, "font cache directory error: {}", e),
             FontError::CacheFile(e) => write!(f, "font cache file error: {}", e),
+            FontError::MissingFile(e) => write!(f, "font missing file error: {}", e),
+            FontError::UnknownFont(e) => write!(f, "unknown font error: {}", e),
+            FontError::InvalidId(e) => write!(f, "invalid font id error: {}", e),
         }
     }
 }
@@ -1004,6 +3700,9 @@ impl Error for FontError {
             FontError::Deserialize(e) => Some(e),
             FontError::CacheDir(e) => Some(e),
             FontError::CacheFile(e) => Some(e),
+            FontError::MissingFile(e) => Some(e),
+            FontError::UnknownFont(e) => Some(e),
+            FontError::InvalidId(e) => Some(e),
         }
     }
 }
@@ -1026,6 +3725,40 @@ impl Display for StringError {
 }
 impl Error for StringError {}
 
+#[derive(Debug)]
+pub struct UnknownFontError {
+    name: String,
+    suggestions: Vec<String>,
+}
+impl UnknownFontError {
+    pub fn new(name: &str, suggestions: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            suggestions,
+        }
+    }
+
+    /// The name which didn't match.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Similar names from the catalog, closest first.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+impl Display for UnknownFontError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "no font named '{}'", self.name)?;
+        if !self.suggestions.is_empty() {
+            write!(f, "; did you mean '{}'?", self.suggestions.join("', '"))?;
+        }
+        Ok(())
+    }
+}
+impl Error for UnknownFontError {}
+
 "#,
     );
 }
@@ -1034,14 +3767,45 @@ pub fn wrt_fle_lib(fnts: &[Arc<RwLock<Fnt>>], buf: &mut String) {
     buf.push_str(
         r#"
 pub mod category;
+pub mod classification;
+pub mod codepoint;
+pub mod color;
+pub mod cover;
+pub mod designer;
 pub mod error;
+pub mod fallback;
 pub mod family;
 pub mod font;
+pub mod language;
+pub mod license;
+mod name;
+pub mod query;
+pub mod recommend;
+#[cfg(feature = "sample-text")]
+pub mod sample;
+pub mod script;
+pub mod search;
+pub mod stroke;
 pub mod subset;
 pub use crate::category::*;
+pub use crate::classification::*;
+pub use crate::codepoint::*;
+pub use crate::color::*;
+pub use crate::cover::*;
+pub use crate::designer::*;
 pub use crate::error::*;
+pub use crate::fallback::*;
 pub use crate::family::*;
 pub use crate::font::*;
+pub use crate::language::*;
+pub use crate::license::*;
+pub use crate::query::*;
+pub use crate::recommend::*;
+#[cfg(feature = "sample-text")]
+pub use crate::sample::*;
+pub use crate::script::*;
+pub use crate::search::*;
+pub use crate::stroke::*;
 pub use crate::subset::*;
 "#,
     );
@@ -1094,15 +3858,342 @@ pub use crate::subset::*;
     buf.push_str("    use super::*;\n");
     buf.push_str("    use ttf_parser::Face;\n");
 
+    // Tests of a known font are enabled with the font.
+    let abz_cfg = fnts
+        .iter()
+        .find(|o| o.read().unwrap().variant == "ABeeZeeRegular")
+        .map(|o| cfg_feature("    ", o.read().unwrap().features()));
+
     // Test Family-Font id casting.
+    if let Some(abz_cfg) = &abz_cfg {
+        buf.push('\n');
+        buf.push_str("    #[test]\n");
+        buf.push_str(abz_cfg);
+        buf.push_str("    fn test_cast_family_font() {\n");
+        buf.push_str("        let fam = Family::ABeeZee;\n");
+        buf.push_str("        let fnt = Font::ABeeZeeRegular;\n");
+        buf.push_str("        assert_eq!(fam, fnt.family());\n");
+        buf.push_str("        assert_eq!(fnt, fam.font());\n");
+        buf.push_str("    }\n");
+    }
+
+    // Test Family-language association.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_families_for_language() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            for lang in fam.languages() {\n");
+    buf.push_str("                assert!(families_for_language(lang).contains(&fam));\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test Family-Script association.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_primary_script() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            if let Some(scr) = fam.primary_script() {\n");
+    buf.push_str("                assert!(scr.families().contains(&fam));\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test primary languages.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_primary_language() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            if let Some(lang) = fam.primary_language() {\n");
+    buf.push_str("                assert_eq!(lang.to_string(), lang.as_str());\n");
+    buf.push_str("                assert!(lang.as_str().starts_with(lang.language()));\n");
+    buf.push_str("                assert!(lang.as_str().ends_with(lang.script_code()));\n");
+    buf.push_str("                if let Some(scr) = lang.script() {\n");
+    buf.push_str("                    assert_eq!(scr.code(), lang.script_code());\n");
+    buf.push_str("                }\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test font metrics.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_metrics() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fnt in Font::iter() {\n");
+    buf.push_str("            let mtr = fnt.metrics();\n");
+    buf.push_str("            assert!(mtr.units_per_em > 0);\n");
+    buf.push_str("            assert!(mtr.ascender > mtr.descender);\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test attribution report.
+    if let Some(abz_cfg) = &abz_cfg {
+        buf.push('\n');
+        buf.push_str("    #[test]\n");
+        buf.push_str(abz_cfg);
+        buf.push_str("    fn test_attribution_report() {\n");
+        buf.push_str("        let fnt = Font::ABeeZeeRegular;\n");
+        buf.push_str("        let txt = attribution_report(&[fnt, fnt]);\n");
+        buf.push_str("        assert_eq!(txt.matches(&fnt.display_name()).count(), 1);\n");
+        buf.push_str("        assert!(!txt.contains(&fnt.name()));\n");
+        buf.push_str("        assert!(txt.contains(&fnt.family().license().url()));\n");
+        buf.push_str("    }\n");
+    }
+
+    // Test font query.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_font_query() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        let qry = FontQuery::new().variable(true).weight(400..=400);\n");
+    buf.push_str("        for fnt in qry.fonts() {\n");
+    buf.push_str("            assert!(fnt.is_variable());\n");
+    buf.push_str("            assert!(fnt.weight_range().contains(&400));\n");
+    buf.push_str("            assert!(qry.families().any(|o| o == fnt.family()));\n");
+    buf.push_str("        }\n");
+    buf.push_str("        assert_eq!(FontQuery::new().fonts().count(), Font::iter().count());\n");
+    buf.push_str(
+        "        for fam in FontQuery::new().brand_font(false).open_source(true).families() {\n",
+    );
+    buf.push_str("            assert!(!fam.is_brand_font() && fam.is_open_source());\n");
+    buf.push_str("        }\n");
+    buf.push_str("        let notos = FontQuery::new().noto(true).families().count();\n");
+    buf.push_str("        let others = FontQuery::new().noto(false).families().count();\n");
+    buf.push_str("        assert_eq!(notos + others, Family::iter().count());\n");
+    buf.push_str("        for clr in ColorCapability::iter() {\n");
+    buf.push_str("            for fam in FontQuery::new().color_capability(clr).families() {\n");
+    buf.push_str("                assert!(fam.color_capabilities().contains(&clr));\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test CSS font matching.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_best_match() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fnt in Font::iter() {\n");
+    buf.push_str("            let style = if fnt.is_italic() { FontStyle::Italic } else { FontStyle::Normal };\n");
+    buf.push_str("            let stretch = *fnt.stretch_range().start();\n");
+    buf.push_str("            let mtc = fnt.family().best_match(fnt.weight(), style, stretch);\n");
+    buf.push_str("            assert_eq!(mtc.is_italic(), fnt.is_italic());\n");
+    buf.push_str("            assert!(mtc.weight_range().contains(&fnt.weight()));\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test display name uniqueness.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_display_names_unique() {\n");
+    buf.push_str("        use std::collections::HashSet;\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        let mut names = HashSet::new();\n");
+    buf.push_str("        for fnt in Font::iter() {\n");
+    buf.push_str("            assert!(names.insert(fnt.display_name()), \"duplicate {}\", fnt.display_name());\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test name lookup.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_from_name() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            let name = fam.display_name().to_uppercase().replace(' ', \"-\");\n");
+    buf.push_str("            assert_eq!(Family::from_name(&name).unwrap(), fam);\n");
+    buf.push_str("        }\n");
+    buf.push_str("        for fnt in Font::iter() {\n");
+    buf.push_str("            assert_eq!(Font::from_name(&fnt.display_name().to_lowercase()).unwrap(), fnt);\n");
+    buf.push_str("            assert_eq!(Font::from_name(fnt.file_name()).unwrap(), fnt);\n");
+    buf.push_str("        }\n");
+    buf.push_str("        let fam = Family::iter().next().unwrap();\n");
+    buf.push_str("        let typo = format!(\"{}x\", fam.display_name());\n");
+    buf.push_str("        match Family::from_name(&typo) {\n");
+    buf.push_str("            Err(FontError::UnknownFont(e)) => assert!(e.suggestions().contains(&fam.display_name())),\n");
+    buf.push_str("            _ => panic!(\"expected an unknown font error\"),\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test search.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_search() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            let results = search(&fam.display_name());\n");
+    buf.push_str("            assert!(results.iter().any(|o| o.0 == fam));\n");
+    buf.push_str("            assert!(results.windows(2).all(|o| o[0].1 >= o[1].1));\n");
+    buf.push_str("        }\n");
+    buf.push_str("        assert!(search(\"\").is_empty());\n");
+    buf.push_str("    }\n");
+
+    // Test character coverage.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_supports_char() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            for (_, rngs) in fam.coverage() {\n");
+    buf.push_str("                for rng in rngs {\n");
+    buf.push_str("                    let txt: String = [*rng.start(), *rng.end()].iter().filter_map(|o| char::from_u32(*o)).collect();\n");
+    buf.push_str("                    assert!(fam.supports_str(&txt));\n");
+    buf.push_str("                    assert!(fam.missing_chars(&txt).is_empty());\n");
+    buf.push_str("                }\n");
+    buf.push_str("            }\n");
+    buf.push_str("            assert_eq!(fam.missing_chars(\"\\u{10FFFF}\\u{10FFFF}\"), vec!['\\u{10FFFF}']);\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test fallback chain.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_fallback_chain() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        let txt = \"Hello, мир! \\u{10FFFF}こんにちは\";\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            let chn = FallbackChain::new(fam, txt);\n");
+    buf.push_str("            assert_eq!(chn.families()[0], fam);\n");
+    buf.push_str("            assert_eq!(chn.runs().first().unwrap().range.start, 0);\n");
+    buf.push_str("            assert_eq!(chn.runs().last().unwrap().range.end, txt.len());\n");
+    buf.push_str(
+        "            assert!(chn.runs().windows(2).all(|o| o[0].range.end == o[1].range.start));\n",
+    );
+    buf.push_str("            assert!(chn.missing_chars().contains(&'\\u{10FFFF}'));\n");
+    buf.push_str("            for run in chn.runs() {\n");
+    buf.push_str(
+        "                let missing = run.family.missing_chars(&txt[run.range.clone()]);\n",
+    );
+    buf.push_str("                assert!(missing.iter().all(|o| o.is_whitespace() || chn.missing_chars().contains(o)));\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test minimal cover.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_minimal_cover() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        let qry = FontQuery::new();\n");
+    buf.push_str("        // Subsets without an enabled family can't be covered.\n");
+    buf.push_str("        let subs: Vec<Subset> = Subset::iter().filter(|o| !o.family_slice().is_empty()).collect();\n");
+    buf.push_str("        let latin = Family::iter().any(|o| o.supports_str(\"AZ\"));\n");
+    buf.push_str("        for cost in [CoverCost::FileCount, CoverCost::FileSize] {\n");
+    buf.push_str("            let fnts = minimal_cover(&subs, &qry, cost);\n");
+    buf.push_str("            for sub in subs.iter() {\n");
+    buf.push_str(
+        "                assert!(fnts.iter().any(|o| o.family().subsets().contains(sub)));\n",
+    );
+    buf.push_str("            }\n");
+    buf.push_str("            assert!(fnts.len() <= subs.len());\n");
+    buf.push_str("            let fnts = minimal_cover_ranges(&[0x41..=0x5A, 0x10FFFF..=0x10FFFF], &qry, cost);\n");
+    buf.push_str("            if latin {\n");
+    buf.push_str("                assert_eq!(fnts.len(), 1);\n");
+    buf.push_str("                assert!(fnts[0].family().supports_str(\"AZ\"));\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("    }\n");
+
+    // Test codepoint index.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_families_for_char() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            for (fst, lst) in fam.codepoint_ranges() {\n");
+    buf.push_str("                for cp in [*fst, *lst, lst + 1] {\n");
+    buf.push_str("                    if let Some(c) = char::from_u32(cp) {\n");
+    buf.push_str("                        let fams = families_for_char(c);\n");
+    buf.push_str(
+        "                        assert_eq!(fams.contains(&fam), fam.supports_char(c));\n",
+    );
+    buf.push_str("                    }\n");
+    buf.push_str("                }\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("        assert!(families_for_char('\\u{10FFFF}').is_empty());\n");
+    buf.push_str("    }\n");
+
+    // Test subset coverage.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_subset_detect() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for sub in Subset::iter() {\n");
+    buf.push_str("            for (fst, lst) in sub.ranges() {\n");
+    buf.push_str(
+        "                if let Some(c) = char::from_u32(*fst).filter(|o| !o.is_whitespace()) {\n",
+    );
+    buf.push_str("                    assert!(sub.contains(c));\n");
+    buf.push_str("                    if sub.is_script() {\n");
+    buf.push_str("                        assert!(Subset::detect(&c.to_string()).iter().any(|o| o.contains(c)));\n");
+    buf.push_str("                    }\n");
+    buf.push_str("                }\n");
+    buf.push_str("                if let Some(c) = char::from_u32(lst + 1) {\n");
+    buf.push_str("                    assert!(!sub.contains(c));\n");
+    buf.push_str("                }\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("        assert!(Subset::detect(\" \\u{10FFFF}\").is_empty());\n");
+    buf.push_str("        for sub in Subset::iter() {\n");
+    buf.push_str(
+        "            assert_eq!(sub.is_script(), !sub.scripts().is_empty(), \"{}\", sub.name());\n",
+    );
+    buf.push_str("            if sub.is_rtl() {\n");
+    buf.push_str("                assert!(sub.is_script());\n");
+    buf.push_str("            }\n");
+    buf.push_str("        }\n");
+    buf.push_str("        assert!(Subset::detect(\"Aa\").iter().all(|o| o.is_script()));\n");
+    buf.push_str("    }\n");
+
+    // Test font recommendation.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_recommend() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        let txt = \"Hello, мир! こんにちは\";\n");
+    buf.push_str("        for cat in Category::iter() {\n");
+    buf.push_str("            let fams = recommend(txt, cat);\n");
+    buf.push_str("            assert!(fams.iter().all(|o| o.category() == cat));\n");
+    buf.push_str("            let cnts: Vec<usize> = fams.iter().map(|o| txt.chars().filter(|c| !c.is_whitespace() && o.supports_char(*c)).count()).collect();\n");
+    buf.push_str("            assert!(cnts.iter().all(|o| *o > 0));\n");
+    buf.push_str("        }\n");
+    buf.push_str("        assert!(recommend(\"\\u{10FFFF}\", Category::SansSerif).is_empty());\n");
+    buf.push_str("    }\n");
+
+    // Test script segmentation.
     buf.push('\n');
     buf.push_str("    #[test]\n");
-    buf.push_str("    #[cfg(feature = \"static\")]\n");
-    buf.push_str("    fn test_cast_family_font() {\n");
-    buf.push_str("        let fam = Family::ABeeZee;\n");
-    buf.push_str("        let fnt = Font::ABeeZeeRegular;\n");
-    buf.push_str("        assert_eq!(fam, fnt.family());\n");
-    buf.push_str("        assert_eq!(fnt, fam.font());\n");
+    buf.push_str("    fn test_segment() {\n");
+    buf.push_str("        let txt = \"¡Hola, мир! こんにちは 世界\";\n");
+    buf.push_str("        let runs = crate::recommend::segment(txt);\n");
+    buf.push_str("        let scrs: Vec<&str> = runs.iter().map(|o| o.0).collect();\n");
+    buf.push_str("        assert_eq!(scrs, [\"Latn\", \"Cyrl\", \"Hira\", \"Hani\"]);\n");
+    buf.push_str("        assert_eq!(&txt[runs[0].1.clone()], \"¡Hola, \");\n");
+    buf.push_str("        assert_eq!(runs[runs.len() - 1].1.end, txt.len());\n");
+    buf.push_str("        assert!(runs.windows(2).all(|o| o[0].1.end == o[1].1.start));\n");
+    buf.push_str("        assert_eq!(&txt[runs[1].1.clone()], \"мир! \");\n");
+    buf.push_str("        assert!(crate::recommend::segment(\" 1, 2\").is_empty());\n");
+    buf.push_str("    }\n");
+
+    // Test id lookup.
+    buf.push('\n');
+    buf.push_str("    #[test]\n");
+    buf.push_str("    fn test_try_from_id() {\n");
+    buf.push_str("        use strum::IntoEnumIterator;\n");
+    buf.push_str("        for fam in Family::iter() {\n");
+    buf.push_str("            assert_eq!(Family::try_from(fam.id()).unwrap(), fam);\n");
+    buf.push_str("            assert_eq!(fam.font().family(), fam);\n");
+    buf.push_str("        }\n");
+    buf.push_str("        for fnt in Font::iter() {\n");
+    buf.push_str("            assert_eq!(Font::try_from(fnt.id()).unwrap(), fnt);\n");
+    buf.push_str("            assert!(fnt.family().font_slice().contains(&fnt));\n");
+    buf.push_str("        }\n");
+    buf.push_str("        assert!(Family::try_from(u32::MAX).is_err());\n");
+    buf.push_str("        assert!(Font::try_from(u32::MAX).is_err());\n");
     buf.push_str("    }\n");
 
     // Test getting each font's data.
@@ -1128,7 +4219,7 @@ pub use crate::subset::*;
 }
 
 pub fn wrt_fle_imgs(fnts: &[Arc<RwLock<Fnt>>], cli: &Client) -> Result<()> {
-    let pth = PathBuf::from("../doc/imgs");
+    let pth = PathBuf::from(DOC_IMGS);
 
     // Re-create image directory.
     // Ensure we don't keep images for deleted fonts.
@@ -1214,7 +4305,11 @@ fn process_font(fnt: &Arc<RwLock<Fnt>>, cli: &Client, mut pth: PathBuf) -> Resul
     Ok(())
 }
 
-pub fn wrt_fle_cargo_toml(dir_pth: &str) -> Result<()> {
+pub fn wrt_fle_cargo_toml(
+    dir_pth: &str,
+    subs: &[Arc<RwLock<Sub>>],
+    cats: &[Arc<RwLock<Cat>>],
+) -> Result<()> {
     let mut pth = PathBuf::from(dir_pth);
     pth.pop();
     pth.push("Cargo");
@@ -1223,16 +4318,49 @@ pub fn wrt_fle_cargo_toml(dir_pth: &str) -> Result<()> {
     // Read the cargo file
     let mut man = cargo_toml::Manifest::from_path(&pth)?;
 
+    // Set the crate version.
+    if let Some(pkg) = man.package.as_mut() {
+        pkg.version = cargo_toml::Inheritable::Set(VERSION.into());
+    }
+
     // Clear any previous features
     man.features.clear();
 
     // Add features.
-    man.features.insert("default".into(), vec![VARIABLE.into()]);
-    man.features
-        .insert(FULL.into(), vec![VARIABLE.into(), STATIC.into()]);
+    man.features.insert(
+        "default".into(),
+        vec![VARIABLE.into(), ALL_SUBSETS.into(), ALL_CATEGORIES.into()],
+    );
+    man.features.insert(
+        FULL.into(),
+        vec![
+            VARIABLE.into(),
+            STATIC.into(),
+            ALL_SUBSETS.into(),
+            ALL_CATEGORIES.into(),
+        ],
+    );
     man.features.insert(VARIABLE.into(), vec![]);
     man.features.insert(STATIC.into(), vec![]);
 
+    // Add a feature per subset and category.
+    man.features.insert(
+        ALL_SUBSETS.into(),
+        subs.iter().map(|o| o.read().unwrap().feature()).collect(),
+    );
+    for sub in subs.iter() {
+        man.features.insert(sub.read().unwrap().feature(), vec![]);
+    }
+    man.features.insert(
+        ALL_CATEGORIES.into(),
+        cats.iter().map(|o| o.read().unwrap().feature()).collect(),
+    );
+    for cat in cats.iter() {
+        man.features.insert(cat.read().unwrap().feature(), vec![]);
+    }
+    man.features.insert(DESCRIPTIONS.into(), vec![]);
+    man.features.insert(SAMPLE_TEXT.into(), vec![]);
+
     // Serialize the mutated manifest back to TOML format
     let toml_string = toml::ser::to_string(&man)?;
 
@@ -1245,23 +4373,146 @@ pub fn wrt_fle_cargo_toml(dir_pth: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn cfg_feature(indent: &str, features: Vec<String>) -> String {
-    if features.len() == 1 {
-        format!("{}#[cfg(feature = \"{}\")]\n", indent, features[0])
-    } else {
-        // #[cfg(any(feature = "feature1", feature = "feature2"))]
-        let mut buf = String::with_capacity(1_024);
-        buf.push_str(indent);
-        buf.push_str("#[cfg(any(");
-        for (idx, feature) in features.iter().enumerate() {
-            if idx != 0 {
-                buf.push_str(", ");
+/// Writes a `cfg` attribute requiring one feature from each group.
+///
+/// For example, `#[cfg(all(any(feature = "variable", feature = "static"), feature = "subset-latin"))]`.
+pub fn cfg_feature(indent: &str, features: Vec<Vec<String>>) -> String {
+    format!("{}#[cfg({})]\n", indent, cfg_predicate(features))
+}
+
+/// Writes a `cfg` attribute which is true when crate features are *not* enabled.
+pub fn cfg_not_feature(indent: &str, features: Vec<Vec<String>>) -> String {
+    format!("{}#[cfg(not({}))]\n", indent, cfg_predicate(features))
+}
+
+/// Writes a row field with a value when `on` is true, and an empty value when `off` is true.
+///
+/// `on` and `off` are `cfg` predicates which exclude each other.
+pub fn gated_field(on: &str, off: &str, field: &str, val: &str, empty: &str) -> String {
+    format!(
+        "        #[cfg({})]\n        {}: {},\n        #[cfg({})]\n        {}: {},\n",
+        on, field, val, off, field, empty
+    )
+}
+
+/// Writes a `cfg` predicate of feature groups.
+///
+/// Features in a group are alternatives. Every group must be enabled.
+/// An empty group, such as a family without subsets, doesn't gate anything,
+/// since `any()` would never be enabled.
+pub fn cfg_predicate(features: Vec<Vec<String>>) -> String {
+    let prds: Vec<String> = features
+        .iter()
+        .filter(|grp| !grp.is_empty())
+        .map(|grp| {
+            if grp.len() == 1 {
+                format!("feature = \"{}\"", grp[0])
+            } else {
+                let prds: Vec<String> =
+                    grp.iter().map(|o| format!("feature = \"{}\"", o)).collect();
+                format!("any({})", prds.join(", "))
             }
-            buf.push_str(&format!("feature = \"{}\"", feature));
+        })
+        .collect();
+    if prds.len() == 1 {
+        prds[0].clone()
+    } else {
+        format!("all({})", prds.join(", "))
+    }
+}
+
+/// Writes a dense index as a slice literal. For example, `&[0, 1, u32::MAX, 3]`.
+///
+/// Each `(key, idx)` pair sets a position. Missing positions are `u32::MAX`.
+pub fn dense_idxs(pairs: impl Iterator<Item = (usize, usize)>) -> String {
+    let mut idxs: Vec<Option<usize>> = Vec::new();
+    for (key, idx) in pairs {
+        if idxs.len() <= key {
+            idxs.resize(key + 1, None);
         }
-        buf.push_str("))]\n");
-        buf
+        idxs[key] = Some(idx);
+    }
+    let idxs: Vec<String> = idxs
+        .iter()
+        .map(|o| match o {
+            Some(idx) => idx.to_string(),
+            None => "u32::MAX".into(),
+        })
+        .collect();
+    format!("&[{}]", idxs.join(", "))
+}
+
+/// Writes member accessors of an enum whose variants group families.
+///
+/// Members are read from `MEMBER_ROWS`, written by [`wrt_member_rows`].
+/// With `slices`, writes cached `family_slice` and `font_slice`
+/// with deprecated `families` and `fonts` copying them.
+/// Otherwise writes `families` and `fonts`.
+pub fn wrt_member_fns(enm: &str, slices: bool, buf: &mut String) {
+    if slices {
+        buf.push_str(&format!(
+            r#"
+    /// Returns families for the [`{enm}`].
+    ///
+    /// The slice is built on the first call for each variant.
+    pub fn family_slice(&self) -> &'static [Family] {{
+        static SLICES: [OnceLock<Vec<Family>>; {enm}::COUNT] = [const {{ OnceLock::new() }}; {enm}::COUNT];
+        SLICES[*self as usize].get_or_init(|| families_at(MEMBER_ROWS[*self as usize]))
+    }}
+
+    /// Returns families for the [`{enm}`].
+    #[deprecated(since = "0.2.0", note = "use `family_slice`, which only allocates on its first call")]
+    pub fn families(&self) -> Vec<Family> {{
+        self.family_slice().to_vec()
+    }}
+
+    /// Returns fonts for the [`{enm}`].
+    ///
+    /// The slice is built on the first call for each variant.
+    pub fn font_slice(&self) -> &'static [Font] {{
+        static SLICES: [OnceLock<Vec<Font>>; {enm}::COUNT] = [const {{ OnceLock::new() }}; {enm}::COUNT];
+        SLICES[*self as usize].get_or_init(|| fonts_of(self.family_slice()))
+    }}
+
+    /// Returns fonts for the [`{enm}`].
+    #[deprecated(since = "0.2.0", note = "use `font_slice`, which only allocates on its first call")]
+    pub fn fonts(&self) -> Vec<Font> {{
+        self.font_slice().to_vec()
+    }}
+"#
+        ));
+    } else {
+        buf.push_str(&format!(
+            r#"
+    /// Returns families for the [`{enm}`].
+    pub fn families(&self) -> Vec<Family> {{
+        families_at(MEMBER_ROWS[*self as usize])
+    }}
+
+    /// Returns fonts for the [`{enm}`].
+    pub fn fonts(&self) -> Vec<Font> {{
+        fonts_of(&self.families())
+    }}
+"#
+        ));
+    }
+}
+
+/// Writes `MEMBER_ROWS`, the family row indexes of each enum variant.
+///
+/// Row indexes don't depend on crate features, so no per-family `cfg` is written.
+pub fn wrt_member_rows(enm: &str, fams: &[Vec<Arc<RwLock<Fam>>>], buf: &mut String) {
+    buf.push('\n');
+    buf.push_str(&format!(
+        "/// [`FamilyRow`](crate::family::FamilyRow) indexes of families for each [`{}`].\n",
+        enm
+    ));
+    buf.push_str("static MEMBER_ROWS: &[&[u32]] = &[\n");
+    for fams in fams.iter() {
+        let rows: Vec<usize> = fams.iter().map(|o| o.read().unwrap().row).collect();
+        buf.push_str(&format!("    &{:?},\n", rows));
     }
+    buf.push_str("];\n");
 }
 
 /// Enumerate items in a sentence.
@@ -1287,18 +4538,408 @@ pub fn comma_and(vals: &[String]) -> String {
                 buf.push_str(&format!("_{}_", val));
             }
 
-            buf
+            buf
+        }
+    }
+}
+
+/// Convert an HTML description to lightweight markdown.
+///
+/// Paragraphs are separated by a blank line.
+pub fn html_to_md(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let selector = Selector::parse("p").unwrap();
+    let mut prgs: Vec<String> = fragment
+        .select(&selector)
+        .map(|elm| {
+            elm_to_md(elm)
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|o| !o.is_empty())
+        .collect();
+    if prgs.is_empty() {
+        // Description without paragraph elements.
+        prgs.push(
+            elm_to_md(fragment.root_element())
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
+    prgs.join("\n\n")
+}
+
+/// Convert an HTML element to lightweight markdown.
+pub fn elm_to_md(elm: ElementRef) -> String {
+    let mut buf = String::new();
+    for nod in elm.children() {
+        match nod.value() {
+            Node::Text(txt) => buf.push_str(txt),
+            Node::Element(e) => {
+                if let Some(child) = ElementRef::wrap(nod) {
+                    let txt = elm_to_md(child);
+                    match (e.name(), e.attr("href")) {
+                        ("a", Some(href)) => buf.push_str(&format!("[{}]({})", txt, href)),
+                        ("b", _) | ("strong", _) => buf.push_str(&format!("**{}**", txt)),
+                        ("i", _) | ("em", _) => buf.push_str(&format!("_{}_", txt)),
+                        ("br", _) => buf.push(' '),
+                        _ => buf.push_str(&txt),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    buf
+}
+
+/// Get an enum variant for a designer name.
+///
+/// Accented Latin letters are folded to ASCII. Other characters are removed.
+pub fn dsg_variant(name: &str) -> String {
+    const FROM: &str = "ÀÁÂÃÄÅàáâãäåĀāĂăĄąÇçĆćČčĎďÈÉÊËèéêëĒēĘęĚěĞğÌÍÎÏìíîïİıĽľĹĺŁłÑñŃńŇňÒÓÔÕÖØòóôõöøŐőŘřŚśŠšŞşŤťÙÚÛÜùúûüŮůŰűÝýÿŹźŻżŽž";
+    const TO: &str = "AAAAAAaaaaaaAaAaAaCcCcCcDdEEEEeeeeEeEeEeGgIIIIiiiiIiLlLlLlNnNnNnOOOOOOooooooOoRrSsSsSsTtUUUUuuuuUuUuYyyZzZzZz";
+    let folded: String = name
+        .chars()
+        .map(|c| match FROM.chars().position(|o| o == c) {
+            Some(idx) => TO.chars().nth(idx).unwrap(),
+            None => c,
+        })
+        .collect();
+    let mut variant: String = folded
+        .to_title_case()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) {
+        variant.insert_str(0, DESIGNER);
+    }
+    variant
+}
+
+pub fn rc<T>(v: T) -> Arc<RwLock<T>> {
+    Arc::new(RwLock::new(v))
+}
+
+pub fn sub_variant(name: String) -> String {
+    name.to_title_case().replace(' ', "")
+}
+
+/// Get the human readable name of a subset. For example, _Latin Extended_.
+pub fn sub_name(name: &str) -> String {
+    match SUBSET_NAMES.iter().find(|(o, _)| *o == name) {
+        Some((_, txt)) => txt.to_string(),
+        None => name.replace("-ext", " Extended").to_title_case(),
+    }
+}
+
+/// Get ISO 15924 script codes of a subset.
+///
+/// Subsets named after a script, such as `cyrillic-ext`, are matched by name.
+pub fn sub_scripts(name: &str) -> Vec<String> {
+    if let Some((_, codes)) = SUBSET_SCRIPTS.iter().find(|(o, _)| *o == name) {
+        return codes.iter().map(|o| o.to_string()).collect();
+    }
+    let key = name.trim_end_matches("-ext").replace('-', " ");
+    SCRIPT_NAMES
+        .iter()
+        .filter(|(_, o)| o.to_lowercase().replace('-', " ") == key)
+        .map(|(code, _)| code.to_string())
+        .collect()
+}
+
+/// Subset names which title case doesn't produce.
+pub const SUBSET_NAMES: &[(&str, &str)] = &[
+    ("chinese-hongkong", "Chinese (Hong Kong)"),
+    ("chinese-simplified", "Chinese (Simplified)"),
+    ("chinese-traditional", "Chinese (Traditional)"),
+    ("nko", "N'Ko"),
+];
+
+/// The subset holding the glyphs of a family name.
+pub const MENU_SUBSET: &str = "menu";
+
+/// Script codes of subsets not named after a script.
+pub const SUBSET_SCRIPTS: &[(&str, &[&str])] = &[
+    ("chinese-hongkong", &["Hant"]),
+    ("chinese-simplified", &["Hans"]),
+    ("chinese-traditional", &["Hant"]),
+    ("emoji", &["Zsye"]),
+    ("japanese", &["Jpan"]),
+    ("korean", &["Kore"]),
+    ("indic-siyaq-numbers", &["Arab"]),
+    ("math", &["Zmth"]),
+    ("mayan-numerals", &["Maya"]),
+    ("menu", &[]),
+    ("meroitic", &["Mero", "Merc"]),
+    ("music", &["Zsym"]),
+    ("nko", &["Nkoo"]),
+    ("ottoman-siyaq-numbers", &["Arab"]),
+    ("symbols", &["Zsym"]),
+    ("symbols2", &["Zsym"]),
+    ("tamil-supplement", &["Taml"]),
+    ("vietnamese", &["Latn"]),
+];
+
+/// ISO 15924 codes of scripts written right-to-left.
+pub const RTL_SCRIPTS: &[&str] = &[
+    "Adlm", "Arab", "Armi", "Avst", "Chrs", "Cprt", "Elym", "Hatr", "Hebr", "Hung", "Khar", "Lydi",
+    "Mand", "Mani", "Mend", "Narb", "Nbat", "Nkoo", "Orkh", "Ougr", "Palm", "Phli", "Phlp", "Phnx",
+    "Prti", "Rohg", "Samr", "Sarb", "Sogd", "Sogo", "Syrc", "Thaa", "Yezi",
+];
+
+/// Get the name of an ISO 15924 script code.
+///
+/// Returns the code for an unknown script.
+pub fn scr_name(code: &str) -> String {
+    match SCRIPT_NAMES.iter().find(|(o, _)| *o == code) {
+        Some((_, name)) => name.to_string(),
+        None => code.to_string(),
+    }
+}
+
+/// Parse unicode codepoint ranges. For example, `32-126,160`.
+pub fn parse_ranges(txt: &str) -> Vec<(u32, u32)> {
+    txt.split(',')
+        .filter(|o| !o.is_empty())
+        .map(|prt| {
+            let mut prts = prt.split('-').map(|s| s.parse::<u32>().unwrap());
+            let fst = prts.next().unwrap();
+            let lst = prts.next().unwrap_or(fst);
+            (fst, lst)
+        })
+        .collect()
+}
+
+/// Sort and merge overlapping or adjacent codepoint ranges.
+pub fn merge_ranges(mut rngs: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    rngs.sort_unstable();
+    let mut ret: Vec<(u32, u32)> = Vec::with_capacity(rngs.len());
+    for (fst, lst) in rngs {
+        match ret.last_mut() {
+            Some(prv) if fst <= prv.1.saturating_add(1) => prv.1 = prv.1.max(lst),
+            _ => ret.push((fst, lst)),
         }
     }
+    ret
 }
 
-pub fn rc<T>(v: T) -> Arc<RwLock<T>> {
-    Arc::new(RwLock::new(v))
+/// Split text into lowercase search words.
+pub fn search_words(txt: &str) -> Vec<String> {
+    let mut wrds: Vec<String> = txt
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|o| !o.is_empty())
+        .map(|o| o.to_lowercase())
+        .collect();
+    wrds.sort_unstable();
+    wrds.dedup();
+    wrds
 }
 
-pub fn sub_variant(name: String) -> String {
-    name.to_title_case().replace(' ', "")
-}
+/// Common words skipped when indexing descriptions.
+pub const STOP_WORDS: &[&str] = &[
+    "and", "are", "but", "can", "for", "from", "has", "have", "its", "not", "that", "the", "their",
+    "there", "these", "this", "was", "were", "which", "with",
+];
+
+/// License file names, variants, names, and URLs.
+pub const LICENSES: &[(&str, &str, &str, &str)] = &[
+    (
+        "OFL.txt",
+        "Ofl",
+        "SIL Open Font License, Version 1.1",
+        "https://openfontlicense.org",
+    ),
+    (
+        "LICENSE.txt",
+        "Apache2",
+        "Apache License, Version 2.0",
+        "https://www.apache.org/licenses/LICENSE-2.0",
+    ),
+    (
+        "UFL.txt",
+        "Ufl",
+        "Ubuntu Font Licence, Version 1.0",
+        "https://ubuntu.com/legal/font-licence",
+    ),
+];
+
+/// ISO 15924 script codes and names.
+pub const SCRIPT_NAMES: &[(&str, &str)] = &[
+    ("Adlm", "Adlam"),
+    ("Aghb", "Caucasian Albanian"),
+    ("Ahom", "Ahom"),
+    ("Arab", "Arabic"),
+    ("Armi", "Imperial Aramaic"),
+    ("Armn", "Armenian"),
+    ("Avst", "Avestan"),
+    ("Bali", "Balinese"),
+    ("Bamu", "Bamum"),
+    ("Bass", "Bassa Vah"),
+    ("Batk", "Batak"),
+    ("Beng", "Bengali"),
+    ("Bhks", "Bhaiksuki"),
+    ("Bopo", "Bopomofo"),
+    ("Brah", "Brahmi"),
+    ("Brai", "Braille"),
+    ("Bugi", "Buginese"),
+    ("Buhd", "Buhid"),
+    ("Cakm", "Chakma"),
+    ("Cans", "Canadian Aboriginal"),
+    ("Cari", "Carian"),
+    ("Cham", "Cham"),
+    ("Cher", "Cherokee"),
+    ("Chrs", "Chorasmian"),
+    ("Copt", "Coptic"),
+    ("Cpmn", "Cypro-Minoan"),
+    ("Cprt", "Cypriot"),
+    ("Cyrl", "Cyrillic"),
+    ("Deva", "Devanagari"),
+    ("Diak", "Dives Akuru"),
+    ("Dogr", "Dogra"),
+    ("Dsrt", "Deseret"),
+    ("Dupl", "Duployan"),
+    ("Egyp", "Egyptian Hieroglyphs"),
+    ("Elba", "Elbasan"),
+    ("Elym", "Elymaic"),
+    ("Ethi", "Ethiopic"),
+    ("Geor", "Georgian"),
+    ("Glag", "Glagolitic"),
+    ("Gong", "Gunjala Gondi"),
+    ("Gonm", "Masaram Gondi"),
+    ("Goth", "Gothic"),
+    ("Gran", "Grantha"),
+    ("Grek", "Greek"),
+    ("Gujr", "Gujarati"),
+    ("Guru", "Gurmukhi"),
+    ("Hang", "Hangul"),
+    ("Hani", "Han"),
+    ("Hano", "Hanunoo"),
+    ("Hans", "Simplified Han"),
+    ("Hant", "Traditional Han"),
+    ("Hatr", "Hatran"),
+    ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"),
+    ("Hluw", "Anatolian Hieroglyphs"),
+    ("Hmng", "Pahawh Hmong"),
+    ("Hmnp", "Nyiakeng Puachue Hmong"),
+    ("Hung", "Old Hungarian"),
+    ("Ital", "Old Italic"),
+    ("Java", "Javanese"),
+    ("Jpan", "Japanese"),
+    ("Kali", "Kayah Li"),
+    ("Kana", "Katakana"),
+    ("Kawi", "Kawi"),
+    ("Khar", "Kharoshthi"),
+    ("Khmr", "Khmer"),
+    ("Khoj", "Khojki"),
+    ("Kits", "Khitan Small Script"),
+    ("Knda", "Kannada"),
+    ("Kore", "Korean"),
+    ("Kthi", "Kaithi"),
+    ("Lana", "Tai Tham"),
+    ("Laoo", "Lao"),
+    ("Latn", "Latin"),
+    ("Lepc", "Lepcha"),
+    ("Limb", "Limbu"),
+    ("Lina", "Linear A"),
+    ("Linb", "Linear B"),
+    ("Lisu", "Lisu"),
+    ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"),
+    ("Mahj", "Mahajani"),
+    ("Maka", "Makasar"),
+    ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"),
+    ("Marc", "Marchen"),
+    ("Maya", "Mayan Hieroglyphs"),
+    ("Medf", "Medefaidrin"),
+    ("Mend", "Mende Kikakui"),
+    ("Merc", "Meroitic Cursive"),
+    ("Mero", "Meroitic Hieroglyphs"),
+    ("Mlym", "Malayalam"),
+    ("Modi", "Modi"),
+    ("Mong", "Mongolian"),
+    ("Mroo", "Mro"),
+    ("Mtei", "Meetei Mayek"),
+    ("Mult", "Multani"),
+    ("Mymr", "Myanmar"),
+    ("Nagm", "Nag Mundari"),
+    ("Nand", "Nandinagari"),
+    ("Narb", "Old North Arabian"),
+    ("Nbat", "Nabataean"),
+    ("Newa", "Newa"),
+    ("Nkoo", "N'Ko"),
+    ("Nshu", "Nushu"),
+    ("Ogam", "Ogham"),
+    ("Olck", "Ol Chiki"),
+    ("Orkh", "Old Turkic"),
+    ("Orya", "Oriya"),
+    ("Osge", "Osage"),
+    ("Osma", "Osmanya"),
+    ("Ougr", "Old Uyghur"),
+    ("Palm", "Palmyrene"),
+    ("Pauc", "Pau Cin Hau"),
+    ("Perm", "Old Permic"),
+    ("Phag", "Phags-pa"),
+    ("Phli", "Inscriptional Pahlavi"),
+    ("Phlp", "Psalter Pahlavi"),
+    ("Phnx", "Phoenician"),
+    ("Plrd", "Miao"),
+    ("Prti", "Inscriptional Parthian"),
+    ("Rjng", "Rejang"),
+    ("Rohg", "Hanifi Rohingya"),
+    ("Runr", "Runic"),
+    ("Samr", "Samaritan"),
+    ("Sarb", "Old South Arabian"),
+    ("Saur", "Saurashtra"),
+    ("Sgnw", "SignWriting"),
+    ("Shaw", "Shavian"),
+    ("Shrd", "Sharada"),
+    ("Sidd", "Siddham"),
+    ("Sind", "Khudawadi"),
+    ("Sinh", "Sinhala"),
+    ("Sogd", "Sogdian"),
+    ("Sogo", "Old Sogdian"),
+    ("Sora", "Sora Sompeng"),
+    ("Soyo", "Soyombo"),
+    ("Sund", "Sundanese"),
+    ("Sylo", "Syloti Nagri"),
+    ("Syrc", "Syriac"),
+    ("Tagb", "Tagbanwa"),
+    ("Takr", "Takri"),
+    ("Tale", "Tai Le"),
+    ("Talu", "New Tai Lue"),
+    ("Taml", "Tamil"),
+    ("Tang", "Tangut"),
+    ("Tavt", "Tai Viet"),
+    ("Telu", "Telugu"),
+    ("Tfng", "Tifinagh"),
+    ("Tglg", "Tagalog"),
+    ("Thaa", "Thaana"),
+    ("Thai", "Thai"),
+    ("Tibt", "Tibetan"),
+    ("Tirh", "Tirhuta"),
+    ("Tnsa", "Tangsa"),
+    ("Toto", "Toto"),
+    ("Ugar", "Ugaritic"),
+    ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"),
+    ("Wara", "Warang Citi"),
+    ("Wcho", "Wancho"),
+    ("Xpeo", "Old Persian"),
+    ("Xsux", "Cuneiform"),
+    ("Yezi", "Yezidi"),
+    ("Yiii", "Yi"),
+    ("Zanb", "Zanabazar Square"),
+    ("Zinh", "Inherited"),
+    ("Zmth", "Mathematical Notation"),
+    ("Zsye", "Emoji"),
+    ("Zsym", "Symbols"),
+    ("Zyyy", "Common"),
+];
 
 // Request URL: https://fonts.google.com/metadata/fonts/Roboto
 // Request Method:GET
@@ -1316,6 +4957,73 @@ pub fn sub_variant(name: String) -> String {
 
 /// ![Google Fonts](https://www.gstatic.com/images/icons/material/apps/fonts/1x/catalog/checkout/google_web.png=w200)
 
+/// Get unicode script ranges with ISO 15924 codes from network or cache.
+///
+/// Adjacent ranges of a script are merged.
+/// The _Common_, _Inherited_ and _Unknown_ scripts are skipped.
+pub fn get_script_ranges(cli: &Client) -> Result<Vec<(u32, u32, String)>> {
+    // Map script names to codes. For example, `sc ; Latn ; Latin`.
+    let als = get_ucd(cli, "PropertyValueAliases.txt")?;
+    let codes: HashMap<&str, &str> = als
+        .lines()
+        .map(|o| o.split('#').next().unwrap())
+        .filter_map(|o| {
+            let prts: Vec<&str> = o.split(';').map(|o| o.trim()).collect();
+            match prts.as_slice() {
+                ["sc", code, name, ..] => Some((*name, *code)),
+                _ => None,
+            }
+        })
+        .collect();
+
+    // Parse script ranges. For example, `0041..005A    ; Latin # L&  [26] ...`.
+    let scrs = get_ucd(cli, "Scripts.txt")?;
+    let mut ret: Vec<(u32, u32, String)> = Vec::new();
+    for ln in scrs.lines().map(|o| o.split('#').next().unwrap()) {
+        let Some((rng, name)) = ln.split_once(';') else {
+            continue;
+        };
+        let code = match codes.get(name.trim()) {
+            Some(code) if !["Zyyy", "Zinh", "Zzzz"].contains(code) => code.to_string(),
+            _ => continue,
+        };
+        let mut prts = rng.trim().split("..").map(|o| u32::from_str_radix(o, 16));
+        let fst = prts.next().unwrap()?;
+        let lst = prts.next().unwrap_or(Ok(fst))?;
+        ret.push((fst, lst, code));
+    }
+    ret.sort_unstable();
+    ret.dedup_by(|nxt, prv| {
+        let mrg = prv.2 == nxt.2 && prv.1 + 1 == nxt.0;
+        if mrg {
+            prv.1 = nxt.1;
+        }
+        mrg
+    });
+
+    Ok(ret)
+}
+
+/// Get a unicode character database file from network or cache.
+pub fn get_ucd(cli: &Client, name: &str) -> Result<String> {
+    // Create file path.
+    let mut pth = cache_dir();
+    pth.push(name);
+
+    // Load cached file if exists.
+    if pth.exists() {
+        return Ok(fs::read_to_string(pth)?);
+    }
+
+    let url = format!("https://www.unicode.org/Public/UCD/latest/ucd/{}", name);
+    let txt = cli.get(url).send()?.error_for_status()?.text()?;
+
+    // Write the data to disk for caching.
+    fs::write(pth, &txt)?;
+
+    Ok(txt)
+}
+
 /// Get a metadata list for font families.
 pub fn get_family_metadata_list(cli: &Client) -> Result<Vec<FamilyMetadata>> {
     let txt = cli
@@ -1454,6 +5162,33 @@ pub struct FontDetails {
     pub line_height: f32,
 }
 
+impl Fonts {
+    /// Get font details for a weight and style.
+    pub fn details(&self, weight: u16, italic: bool) -> Option<&FontDetails> {
+        match (weight, italic) {
+            (100, false) => self.normal_100.as_ref(),
+            (100, true) => self.italic_100i.as_ref(),
+            (200, false) => self.normal_200.as_ref(),
+            (200, true) => self.italic_200i.as_ref(),
+            (300, false) => self.normal_300.as_ref(),
+            (300, true) => self.italic_300i.as_ref(),
+            (400, false) => self.normal_400.as_ref(),
+            (400, true) => self.italic_400i.as_ref(),
+            (500, false) => self.normal_500.as_ref(),
+            (500, true) => self.italic_500i.as_ref(),
+            (600, false) => self.normal_600.as_ref(),
+            (600, true) => self.italic_600i.as_ref(),
+            (700, false) => self.normal_700.as_ref(),
+            (700, true) => self.italic_700i.as_ref(),
+            (800, false) => self.normal_800.as_ref(),
+            (800, true) => self.italic_800i.as_ref(),
+            (900, false) => self.normal_900.as_ref(),
+            (900, true) => self.italic_900i.as_ref(),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
 #[serde(rename_all = "camelCase")]
 pub struct Axis {
@@ -1463,6 +5198,21 @@ pub struct Axis {
     pub max: f32,
 }
 
+/// Converts an OS/2 width class to a CSS stretch percentage.
+pub fn width_percentage(cls: u16) -> f32 {
+    match cls {
+        1 => 50.0,
+        2 => 62.5,
+        3 => 75.0,
+        4 => 87.5,
+        6 => 112.5,
+        7 => 125.0,
+        8 => 150.0,
+        9 => 200.0,
+        _ => 100.0,
+    }
+}
+
 pub fn cache_dir() -> PathBuf {
     CACHE_DIR.clone()
 }
@@ -1476,9 +5226,18 @@ pub struct FamilyFileList {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Manifest {
+    #[serde(default)]
+    files: Vec<FileContent>,
     file_refs: Vec<FileRef>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileContent {
+    filename: String,
+    contents: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FileRef {
@@ -1497,20 +5256,51 @@ pub struct Date {
 #[derive(Debug, Clone)]
 pub struct Fam {
     pub id: u32,
+    /// The index in `FAMILY_ROWS`.
+    pub row: usize,
     pub name: String,
     pub variant: String,
     pub cat: Arc<RwLock<Cat>>,
+    pub scr: Option<Arc<RwLock<Scr>>>,
+    pub stk: Option<Arc<RwLock<Stk>>>,
+    pub lic: Option<Arc<RwLock<Lic>>>,
     pub meta: FamilyMetadata,
     pub fnts: Vec<Arc<RwLock<Fnt>>>,
     pub subs: Vec<Arc<RwLock<Sub>>>,
+    pub clrs: Vec<Arc<RwLock<Clr>>>,
+    pub clss: Vec<Arc<RwLock<Cls>>>,
+    pub dsgs: Vec<Arc<RwLock<Dsg>>>,
 }
 #[derive(Debug, Clone)]
 pub struct Fnt {
+    /// The id offset from the family id.
     pub id: u32,
+    /// The index in the family file list.
+    pub fle_idx: usize,
+    /// The index in `FONT_ROWS`.
+    pub row: usize,
     pub name: String,
     pub variant: String,
     pub fam: Arc<RwLock<Fam>>,
     pub subs: Vec<Arc<RwLock<Sub>>>,
+    pub mtr: Option<Mtr>,
+    pub fle_name: String,
+}
+
+/// Metrics read from font data.
+#[derive(Debug, Clone)]
+pub struct Mtr {
+    pub line_height: Option<f32>,
+    pub units_per_em: u16,
+    pub ascender: i16,
+    pub descender: i16,
+    pub line_gap: i16,
+    pub cap_height: Option<i16>,
+    pub x_height: Option<i16>,
+    pub weight: u16,
+    pub italic: bool,
+    pub stretch: f32,
+    pub file_size: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -1518,7 +5308,62 @@ pub struct Cat {
     pub name: String,
     pub variant: String,
     pub fams: Vec<Arc<RwLock<Fam>>>,
-    pub fnts: Vec<Arc<RwLock<Fnt>>>,
+}
+
+impl Cat {
+    /// The crate feature enabling the category. For example, `category-sans-serif`.
+    pub fn feature(&self) -> String {
+        format!(
+            "category-{}",
+            self.name.to_lowercase().replace([' ', '_'], "-")
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Scr {
+    pub code: String,
+    pub name: String,
+    pub variant: String,
+    pub fams: Vec<Arc<RwLock<Fam>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Clr {
+    pub name: String,
+    pub variant: String,
+    pub fams: Vec<Arc<RwLock<Fam>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Stk {
+    pub name: String,
+    pub variant: String,
+    pub fams: Vec<Arc<RwLock<Fam>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cls {
+    pub name: String,
+    pub variant: String,
+    pub meta_name: String,
+    pub fams: Vec<Arc<RwLock<Fam>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lic {
+    pub name: String,
+    pub variant: String,
+    pub url: String,
+    pub file_name: String,
+    pub fams: Vec<Arc<RwLock<Fam>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Dsg {
+    pub name: String,
+    pub variant: String,
+    pub fams: Vec<Arc<RwLock<Fam>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -1526,21 +5371,121 @@ pub struct Sub {
     pub name: String,
     pub variant: String,
     pub fams: Vec<Arc<RwLock<Fam>>>,
-    pub fnts: Vec<Arc<RwLock<Fnt>>>,
+}
+
+impl Sub {
+    /// The crate feature enabling the subset. For example, `subset-latin-ext`.
+    pub fn feature(&self) -> String {
+        format!("subset-{}", self.name)
+    }
+}
+
+/// Ids of families and fonts from every generation.
+///
+/// Checked in so existing families and fonts keep their ids when families
+/// are added or removed upstream. Removed entries are kept so ids aren't reused.
+/// Families are keyed by `Family` variant. Fonts are keyed by `Font` variant.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IdRegistry {
+    pub families: BTreeMap<String, FamIds>,
+}
+
+/// Registered ids of a family and the id offsets of its fonts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FamIds {
+    pub id: u32,
+    pub fonts: BTreeMap<String, u32>,
+}
+
+impl IdRegistry {
+    /// Reads the registry. Empty when the file doesn't exist yet.
+    pub fn load(pth: &str) -> Result<IdRegistry> {
+        if !Path::new(pth).exists() {
+            return Ok(IdRegistry::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(pth)?)?)
+    }
+
+    pub fn save(&self, pth: &str) -> Result<()> {
+        fs::write(pth, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the registered id of a family, registering a new id if needed.
+    pub fn family_id(&mut self, fam_variant: &str) -> u32 {
+        if let Some(fam_ids) = self.families.get(fam_variant) {
+            return fam_ids.id;
+        }
+        let id = self
+            .families
+            .values()
+            .map(|o| o.id + FAMILY_ID_INCREMENT)
+            .max()
+            .unwrap_or(0);
+        self.families.insert(
+            fam_variant.into(),
+            FamIds {
+                id,
+                fonts: BTreeMap::new(),
+            },
+        );
+        id
+    }
+
+    /// Returns the registered id offset of a font, registering a new offset if needed.
+    pub fn font_id(&mut self, fam_variant: &str, fnt_variant: &str) -> Result<u32> {
+        let fam_ids = self
+            .families
+            .get_mut(fam_variant)
+            .ok_or_else(|| anyhow!("unregistered family {}", fam_variant))?;
+        if let Some(id) = fam_ids.fonts.get(fnt_variant) {
+            return Ok(*id);
+        }
+        let id = fam_ids.fonts.values().map(|o| o + 1).max().unwrap_or(0);
+        if id >= FAMILY_ID_INCREMENT {
+            return Err(anyhow!("too many fonts in family {}", fam_variant));
+        }
+        fam_ids.fonts.insert(fnt_variant.into(), id);
+        Ok(id)
+    }
 }
 
 impl Fam {
-    pub fn features(&self) -> Vec<String> {
-        let mut ret = Vec::new();
+    /// Get the display name. Same as the name unless Google Fonts displays the family differently.
+    pub fn display_name(&self) -> String {
+        self.meta
+            .display_name
+            .clone()
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    /// Feature groups enabling the family.
+    ///
+    /// One technology, one subset and the category feature must be enabled.
+    pub fn features(&self) -> Vec<Vec<String>> {
+        let mut tech = Vec::new();
         if self.fnts.iter().any(|o| o.read().unwrap().is_variable()) {
-            ret.push(VARIABLE.into());
+            tech.push(VARIABLE.into());
         }
         if self.fnts.iter().any(|o| o.read().unwrap().is_static()) {
-            ret.push(STATIC.into());
+            tech.push(STATIC.into());
         }
+        let mut ret = vec![tech];
+        ret.extend(self.content_features());
         ret
     }
 
+    /// Subset and category feature groups of the family.
+    pub fn content_features(&self) -> Vec<Vec<String>> {
+        vec![
+            self.subs
+                .iter()
+                .map(|o| o.read().unwrap().feature())
+                .collect(),
+            vec![self.cat.read().unwrap().feature()],
+        ]
+    }
+
     /// Get a file list for the font family.
     pub fn get_file_list(&self, cli: &Client) -> Result<Manifest> {
         // Create file path.
@@ -1585,6 +5530,17 @@ impl Fam {
         Ok(ret.manifest)
     }
 
+    /// Get sorted and merged codepoint ranges supported by the font family.
+    pub fn codepoint_ranges(&self, cli: &Client) -> Result<Vec<(u32, u32)>> {
+        let dtl = self.get_metadata_detail(cli)?;
+        Ok(merge_ranges(
+            dtl.coverage
+                .values()
+                .flat_map(|o| parse_ranges(o))
+                .collect(),
+        ))
+    }
+
     /// Get metadata detail for the font family.
     pub fn get_metadata_detail(&self, cli: &Client) -> Result<FamilyMetadataDetail> {
         // Create file path.
@@ -1628,36 +5584,9 @@ impl Fam {
 
         Ok(ret)
     }
-}
-
-impl Fnt {
-    /// Get the font name suffix. For example, _regular_.
-    pub fn name_suffix(&self) -> String {
-        self.name
-            .replace(&self.fam.read().unwrap().variant, "")
-            .trim()
-            .to_title_case()
-            .to_lowercase()
-    }
-
-    // Get the font function name.
-    pub fn fn_name(&self) -> String {
-        let suffix = self
-            .variant
-            .replace(&self.fam.read().unwrap().variant, "")
-            .trim()
-            .to_title_case()
-            .to_lowercase();
-        format!(
-            "{} {}",
-            self.fam.read().unwrap().name.to_lowercase(),
-            suffix
-        )
-        .replace(' ', "_")
-    }
 
-    /// Get sample text from network or cache.
-    pub fn get_sampletext(&self, cli: &Client) -> Result<String> {
+    /// Get sample text for the font family from network or cache.
+    pub fn get_sampletext(&self, cli: &Client) -> Result<FamilySampleText> {
         // Create file path.
         let mut pth = cache_dir();
         pth.push(format!("{}_sampletext", &self.variant));
@@ -1668,14 +5597,14 @@ impl Fnt {
             let fle = File::open(pth)?;
             let rdr = BufReader::new(fle);
             let ret: FamilySampleText = serde_json::from_reader(rdr)?;
-            return Ok(ret.sample_text.txt());
+            return Ok(ret);
         }
 
         // Get the sample text from the network.
         let txt = cli
             .get("https://fonts.google.com/sampletext")
             .query(&[
-                ("family", self.fam.read().unwrap().name.as_str()),
+                ("family", self.name.as_str()),
                 // ("paragraphOnly", "true"),
             ])
             .send()?
@@ -1698,6 +5627,157 @@ impl Fnt {
         // Deserialize JSON to struct.
         let ret: FamilySampleText = serde_json::from_str(txt)?;
 
+        Ok(ret)
+    }
+}
+
+/// Title cases a style. For example, _ExtraBold Italic_ for `ExtraBoldItalic`.
+fn title_style(style: &str) -> String {
+    style
+        .to_title_case()
+        .replace("Extra ", "Extra")
+        .replace("Semi ", "Semi")
+        .replace("Ultra ", "Ultra")
+        .replace("Demi ", "Demi")
+}
+
+/// Get unique display names by font variant.
+///
+/// A display name shared by fonts of different families is kept by the font
+/// whose family name is longest. For example, _Roboto Condensed Bold_ stays with
+/// the _Roboto Condensed_ family. Other fonts sharing it get their file name appended.
+pub fn display_names(fnts: &[Arc<RwLock<Fnt>>]) -> HashMap<String, String> {
+    let mut grps: BTreeMap<String, Vec<Arc<RwLock<Fnt>>>> = BTreeMap::new();
+    for fnt in fnts.iter() {
+        grps.entry(fnt.read().unwrap().display_name())
+            .or_default()
+            .push(fnt.clone());
+    }
+    let mut ret = HashMap::with_capacity(fnts.len());
+    for (name, grp) in grps {
+        let keep = grp
+            .iter()
+            .enumerate()
+            .max_by_key(|(idx, o)| {
+                let len = o.read().unwrap().fam.read().unwrap().display_name().len();
+                (len, usize::MAX - idx)
+            })
+            .map(|(idx, _)| idx)
+            .unwrap();
+        for (idx, fnt) in grp.iter().enumerate() {
+            let fnt = fnt.read().unwrap();
+            if idx == keep {
+                ret.insert(fnt.variant.clone(), name.clone());
+            } else {
+                ret.insert(
+                    fnt.variant.clone(),
+                    format!("{} ({})", name, fnt.file_name()),
+                );
+            }
+        }
+    }
+    ret
+}
+
+impl Fnt {
+    /// The font id. The family id plus the font id offset.
+    pub fn full_id(&self) -> u32 {
+        self.fam.read().unwrap().id + self.id
+    }
+
+    /// Get the font file name without a directory.
+    /// For example, `Roboto-VariableFont_wdth,wght.ttf`.
+    pub fn file_name(&self) -> String {
+        self.fle_name.rsplit('/').next().unwrap().to_string()
+    }
+
+    /// Get variable font axes listed in the file name.
+    /// For example, `wdth` and `wght`.
+    pub fn axes_in_file_name(&self) -> Vec<String> {
+        let file_name = self.file_name();
+        match file_name.find("VariableFont_") {
+            None => Vec::new(),
+            Some(idx) => file_name[idx + 13..file_name.len() - 4]
+                .split(',')
+                .map(|o| o.to_string())
+                .collect(),
+        }
+    }
+
+    /// Get a human readable style name. For example, _Bold Italic_.
+    ///
+    /// Keeps width and optical size parts following the family name in the file name.
+    /// For example, _Condensed Bold_ for `Roboto_Condensed-Bold.ttf`
+    /// and _28pt Bold_ for `RobotoSerif_28pt-Bold.ttf`.
+    pub fn style_name(&self) -> String {
+        let file_name = self.file_name();
+        let stem = &file_name[..file_name.len() - 4];
+        let mut prts = stem.split('-');
+        let prefix = prts
+            .next()
+            .unwrap_or_default()
+            .strip_prefix(self.fam.read().unwrap().variant.as_str())
+            .unwrap_or_default()
+            .trim_start_matches('_');
+        let style = prts
+            .filter(|o| !o.starts_with("VariableFont"))
+            .collect::<Vec<_>>()
+            .join("");
+        let style = if style.is_empty() {
+            "Regular".into()
+        } else {
+            title_style(&style)
+        };
+        if prefix.is_empty() {
+            style
+        } else if style == "Regular" {
+            title_style(prefix)
+        } else {
+            format!("{} {}", title_style(prefix), style)
+        }
+    }
+
+    /// Get a human readable name. For example, _Roboto Italic Variable_.
+    ///
+    /// May be shared by fonts of different families. See [`display_names`].
+    pub fn display_name(&self) -> String {
+        let fam_name = self.fam.read().unwrap().display_name();
+        if self.is_variable() {
+            format!("{} {} Variable", fam_name, self.style_name())
+                .replace(" Regular Variable", " Variable")
+        } else {
+            format!("{} {}", fam_name, self.style_name())
+        }
+    }
+
+    /// Get the font name suffix. For example, _regular_.
+    pub fn name_suffix(&self) -> String {
+        self.name
+            .replace(&self.fam.read().unwrap().variant, "")
+            .trim()
+            .to_title_case()
+            .to_lowercase()
+    }
+
+    // Get the font function name.
+    pub fn fn_name(&self) -> String {
+        let suffix = self
+            .variant
+            .replace(&self.fam.read().unwrap().variant, "")
+            .trim()
+            .to_title_case()
+            .to_lowercase();
+        format!(
+            "{} {}",
+            self.fam.read().unwrap().name.to_lowercase(),
+            suffix
+        )
+        .replace(' ', "_")
+    }
+
+    /// Get sample text from network or cache.
+    pub fn get_sampletext(&self, cli: &Client) -> Result<String> {
+        let ret = self.fam.read().unwrap().get_sampletext(cli)?;
         Ok(ret.sample_text.txt())
     }
 
@@ -1742,6 +5822,80 @@ impl Fnt {
         Ok(byt)
     }
 
+    /// Get vertical metrics from the font data.
+    pub fn metrics(&self, cli: &Client) -> Result<Mtr> {
+        let fnt_dat = self.get(cli)?;
+        let face = Face::parse(&fnt_dat, 0)?;
+
+        // Line height is listed per weight and style.
+        let line_height = self
+            .fam
+            .read()
+            .unwrap()
+            .meta
+            .fonts
+            .details(face.weight().to_number(), face.is_italic())
+            .map(|o| o.line_height);
+
+        Ok(Mtr {
+            line_height,
+            units_per_em: face.units_per_em(),
+            ascender: face.ascender(),
+            descender: face.descender(),
+            line_gap: face.line_gap(),
+            cap_height: face.capital_height(),
+            x_height: face.x_height(),
+            weight: face.weight().to_number(),
+            italic: face.is_italic(),
+            stretch: width_percentage(face.width().to_number()),
+            file_size: fnt_dat.len() as u32,
+        })
+    }
+
+    /// Stretch percentages covered by the font.
+    ///
+    /// A variable font with a `wdth` axis spans the family's axis range.
+    pub fn stretch_range(&self) -> (f32, f32) {
+        let stc = self.mtr.as_ref().map_or(100.0, |o| o.stretch);
+        if !self.axes_in_file_name().iter().any(|o| o == "wdth") {
+            return (stc, stc);
+        }
+        match self
+            .fam
+            .read()
+            .unwrap()
+            .meta
+            .axes
+            .iter()
+            .find(|o| o.tag == "wdth")
+        {
+            Some(axs) => (axs.min, axs.max),
+            None => (stc, stc),
+        }
+    }
+
+    /// Weights covered by the font.
+    ///
+    /// A variable font with a `wght` axis spans the family's axis range.
+    pub fn weight_range(&self) -> (u16, u16) {
+        let wgt = self.mtr.as_ref().map_or(400, |o| o.weight);
+        if !self.axes_in_file_name().iter().any(|o| o == "wght") {
+            return (wgt, wgt);
+        }
+        match self
+            .fam
+            .read()
+            .unwrap()
+            .meta
+            .axes
+            .iter()
+            .find(|o| o.tag == "wght")
+        {
+            Some(axs) => (axs.min as u16, axs.max as u16),
+            None => (wgt, wgt),
+        }
+    }
+
     pub fn is_variable(&self) -> bool {
         self.name.contains("Variable")
     }
@@ -1750,12 +5904,16 @@ impl Fnt {
         !self.is_variable()
     }
 
-    pub fn features(&self) -> Vec<String> {
-        if self.is_variable() {
-            vec![VARIABLE.into()]
+    /// Feature groups enabling the font.
+    pub fn features(&self) -> Vec<Vec<String>> {
+        let tech = if self.is_variable() {
+            VARIABLE.into()
         } else {
-            vec![STATIC.into()]
-        }
+            STATIC.into()
+        };
+        let mut ret = vec![vec![tech]];
+        ret.extend(self.fam.read().unwrap().content_features());
+        ret
     }
 }
 
@@ -1815,3 +5973,105 @@ lazy_static! {
         pth
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_family_id() {
+        let mut ids = IdRegistry::default();
+
+        // Add families.
+        assert_eq!(ids.family_id("ABeeZee"), 0);
+        assert_eq!(ids.family_id("Abel"), FAMILY_ID_INCREMENT);
+        assert_eq!(ids.family_id("ABeeZee"), 0);
+
+        // Remove a family upstream. Its id isn't reused.
+        let mut ids: IdRegistry =
+            serde_json::from_str(&serde_json::to_string(&ids).unwrap()).unwrap();
+        assert_eq!(ids.family_id("ABeeZee"), 0);
+        assert_eq!(ids.family_id("Roboto"), 2 * FAMILY_ID_INCREMENT);
+
+        // Re-add the family. It keeps its id.
+        assert_eq!(ids.family_id("Abel"), FAMILY_ID_INCREMENT);
+
+        // Remove the family with the highest id. A later new family skips it.
+        let mut ids: IdRegistry =
+            serde_json::from_str(&serde_json::to_string(&ids).unwrap()).unwrap();
+        assert_eq!(ids.family_id("ABeeZee"), 0);
+        assert_eq!(ids.family_id("Abel"), FAMILY_ID_INCREMENT);
+        assert_eq!(ids.family_id("Ubuntu"), 3 * FAMILY_ID_INCREMENT);
+    }
+
+    #[test]
+    fn test_font_id() {
+        let mut ids = IdRegistry::default();
+        assert!(ids.font_id("Roboto", "RobotoRegular").is_err());
+        ids.family_id("Roboto");
+
+        // Add fonts.
+        assert_eq!(ids.font_id("Roboto", "RobotoRegular").unwrap(), 0);
+        assert_eq!(ids.font_id("Roboto", "RobotoBold").unwrap(), 1);
+        assert_eq!(ids.font_id("Roboto", "RobotoRegular").unwrap(), 0);
+
+        // Remove a font upstream. Its id isn't reused.
+        let mut ids: IdRegistry =
+            serde_json::from_str(&serde_json::to_string(&ids).unwrap()).unwrap();
+        assert_eq!(ids.font_id("Roboto", "RobotoRegular").unwrap(), 0);
+        assert_eq!(ids.font_id("Roboto", "RobotoItalic").unwrap(), 2);
+
+        // Re-add the font. It keeps its id.
+        assert_eq!(ids.font_id("Roboto", "RobotoBold").unwrap(), 1);
+
+        // Remove a sibling font from the manifest. Later fonts keep their offsets.
+        let mut ids: IdRegistry =
+            serde_json::from_str(&serde_json::to_string(&ids).unwrap()).unwrap();
+        assert_eq!(ids.font_id("Roboto", "RobotoRegular").unwrap(), 0);
+        assert_eq!(ids.font_id("Roboto", "RobotoItalic").unwrap(), 2);
+
+        // Overflow into the next family's ids.
+        for idx in 3..FAMILY_ID_INCREMENT {
+            let fnt_variant = format!("Roboto{}", idx);
+            assert_eq!(ids.font_id("Roboto", &fnt_variant).unwrap(), idx);
+        }
+        assert!(ids.font_id("Roboto", "RobotoOverflow").is_err());
+        assert_eq!(ids.font_id("Roboto", "RobotoBold").unwrap(), 1);
+    }
+
+    #[test]
+    fn test_id_registry() {
+        let txt = fs::read_to_string(ID_REGISTRY).unwrap();
+        let mut ids: IdRegistry = serde_json::from_str(&txt).unwrap();
+
+        // Saving doesn't rewrite the checked-in registry.
+        assert_eq!(serde_json::to_string_pretty(&ids).unwrap(), txt);
+
+        // Every family has fonts with distinct offsets.
+        let prv = ids.clone();
+        for (fam, fam_ids) in prv.families.iter() {
+            assert!(!fam_ids.fonts.is_empty(), "{} has no fonts", fam);
+            let mut offs: Vec<u32> = fam_ids.fonts.values().copied().collect();
+            offs.sort_unstable();
+            offs.dedup();
+            assert_eq!(offs.len(), fam_ids.fonts.len(), "{} reuses an offset", fam);
+        }
+
+        // Registering existing fonts in reverse order, and a new font, keeps existing offsets.
+        for (fam, fam_ids) in prv.families.iter() {
+            for (fnt, off) in fam_ids.fonts.iter().rev() {
+                assert_eq!(ids.font_id(fam, fnt).unwrap(), *off, "{} moved", fnt);
+            }
+            let nxt = fam_ids.fonts.values().max().unwrap() + 1;
+            if nxt < FAMILY_ID_INCREMENT {
+                assert_eq!(ids.font_id(fam, &format!("{}New", fam)).unwrap(), nxt);
+            }
+            assert_eq!(ids.family_id(fam), fam_ids.id);
+        }
+        for (fam, fam_ids) in prv.families.iter() {
+            for (fnt, off) in fam_ids.fonts.iter() {
+                assert_eq!(ids.families[fam].fonts[fnt], *off, "{} rewritten", fnt);
+            }
+        }
+    }
+}